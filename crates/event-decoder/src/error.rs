@@ -0,0 +1,25 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum DecodeError {
+    #[error("invalid transaction json: {0}")]
+    Json(#[from] serde_json::Error),
+
+    #[error("invalid base58 instruction data: {0}")]
+    Base58(#[from] bs58::decode::Error),
+
+    #[error("invalid base64 program data: {0}")]
+    Base64(#[from] base64::DecodeError),
+
+    #[error("failed to deserialize {name}: {source}")]
+    Borsh {
+        name: &'static str,
+        source: std::io::Error,
+    },
+
+    #[error("malformed {name} log line: {line}")]
+    MalformedLog { name: &'static str, line: String },
+
+    #[error("inner instruction references unknown account index {0}")]
+    UnknownAccountIndex(usize),
+}
@@ -0,0 +1,128 @@
+use anchor_lang::{event::EVENT_IX_TAG_LE, prelude::Pubkey, AnchorDeserialize, Discriminator};
+use ape_or_die::events::*;
+
+use crate::DecodeError;
+
+/// An event emitted by the program, either typed (`emit!` / `emit_cpi!`) or
+/// one of the free-form `msg!` lines logged by earlier program versions.
+//  `ConfigUpdated` carries the whole config, events are decoded one at a time
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, Clone, PartialEq)]
+pub enum ProgramEvent {
+    Complete(CompleteEvent),
+    Launch(LaunchEvent),
+    MetadataUpdated(MetadataUpdatedEvent),
+    Swap(SwapEvent),
+    Withdraw(WithdrawEvent),
+    VestedClaimed(VestedClaimedEvent),
+    FeesSwept(FeesSweptEvent),
+    ConfigMigrated(ConfigMigratedEvent),
+    CurveVaultMigrated(CurveVaultMigratedEvent),
+    ConfigUpdated(ConfigUpdatedEvent),
+    ConfigChangeQueued(ConfigChangeQueuedEvent),
+    ConfigChangeExecuted(ConfigChangeExecutedEvent),
+    ConfigChangeCancelled(ConfigChangeCancelledEvent),
+    CurveParamsUpdated(CurveParamsUpdatedEvent),
+    LaunchLimitsUpdated(LaunchLimitsUpdatedEvent),
+    MetadataPolicyUpdated(MetadataPolicyUpdatedEvent),
+    LaunchFeeDiscountsUpdated(LaunchFeeDiscountsUpdatedEvent),
+    AuthorityNominated(AuthorityNominatedEvent),
+    AuthorityAccepted(AuthorityAcceptedEvent),
+    AuthorityRenounced(AuthorityRenouncedEvent),
+    NominationCancelled(NominationCancelledEvent),
+    RoleNominated(RoleNominatedEvent),
+    RoleAccepted(RoleAcceptedEvent),
+    RoleRevoked(RoleRevokedEvent),
+    Paused(PausedEvent),
+    Audit(AuditEvent),
+
+    LegacySwap(LegacySwapLog),
+    LegacyNewToken(LegacyNewTokenLog),
+    LegacyReserves(LegacyReservesLog),
+}
+
+/// `SwapEvent: <user> <direction> <amount_out>`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LegacySwapLog {
+    pub user: Pubkey,
+    pub direction: u8,
+    pub amount_out: u64,
+}
+
+/// `NewToken: <mint> <creator>`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LegacyNewTokenLog {
+    pub mint: Pubkey,
+    pub creator: Pubkey,
+}
+
+/// `Reserves: <reserve_token> <reserve_lamport>`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LegacyReservesLog {
+    pub reserve_token: u64,
+    pub reserve_lamport: u64,
+}
+
+fn deserialize<E: AnchorDeserialize>(name: &'static str, data: &[u8]) -> Result<E, DecodeError> {
+    E::try_from_slice(data).map_err(|source| DecodeError::Borsh { name, source })
+}
+
+/// Decodes event data (discriminator followed by the borsh payload), as found
+/// in `Program data:` logs. Unknown discriminators yield `None`.
+pub fn decode_event_data(data: &[u8]) -> Result<Option<ProgramEvent>, DecodeError> {
+    if data.len() < 8 {
+        return Ok(None);
+    }
+    let (discriminator, payload) = data.split_at(8);
+
+    macro_rules! decode {
+        ($($event:ident => $variant:ident),* $(,)?) => {
+            $(
+                if discriminator == $event::DISCRIMINATOR {
+                    return deserialize(stringify!($event), payload)
+                        .map(|event| Some(ProgramEvent::$variant(event)));
+                }
+            )*
+        };
+    }
+
+    decode!(
+        CompleteEvent => Complete,
+        LaunchEvent => Launch,
+        MetadataUpdatedEvent => MetadataUpdated,
+        SwapEvent => Swap,
+        WithdrawEvent => Withdraw,
+        VestedClaimedEvent => VestedClaimed,
+        FeesSweptEvent => FeesSwept,
+        ConfigMigratedEvent => ConfigMigrated,
+        CurveVaultMigratedEvent => CurveVaultMigrated,
+        ConfigUpdatedEvent => ConfigUpdated,
+        ConfigChangeQueuedEvent => ConfigChangeQueued,
+        ConfigChangeExecutedEvent => ConfigChangeExecuted,
+        ConfigChangeCancelledEvent => ConfigChangeCancelled,
+        CurveParamsUpdatedEvent => CurveParamsUpdated,
+        LaunchLimitsUpdatedEvent => LaunchLimitsUpdated,
+        MetadataPolicyUpdatedEvent => MetadataPolicyUpdated,
+        LaunchFeeDiscountsUpdatedEvent => LaunchFeeDiscountsUpdated,
+        AuthorityNominatedEvent => AuthorityNominated,
+        AuthorityAcceptedEvent => AuthorityAccepted,
+        AuthorityRenouncedEvent => AuthorityRenounced,
+        NominationCancelledEvent => NominationCancelled,
+        RoleNominatedEvent => RoleNominated,
+        RoleAcceptedEvent => RoleAccepted,
+        RoleRevokedEvent => RoleRevoked,
+        PausedEvent => Paused,
+        AuditEvent => Audit,
+    );
+
+    Ok(None)
+}
+
+/// Decodes the data of an `emit_cpi!` self-invocation. Instructions that are
+/// not event CPIs yield `None`.
+pub fn decode_cpi_event(ix_data: &[u8]) -> Result<Option<ProgramEvent>, DecodeError> {
+    match ix_data.strip_prefix(&EVENT_IX_TAG_LE) {
+        Some(data) => decode_event_data(data),
+        None => Ok(None),
+    }
+}
@@ -0,0 +1,17 @@
+//! Decodes ApeOrDie program events from a transaction's log messages and
+//! inner instructions, e.g. a recorded `getTransaction` response.
+//!
+//! Typed events are read from `Program data:` logs (`emit!`) and from the
+//! program's event self-invocations (`emit_cpi!`). The `SwapEvent`,
+//! `NewToken` and `Reserves` `msg!` lines of earlier program versions are
+//! decoded as legacy events.
+
+mod error;
+mod events;
+mod logs;
+mod transaction;
+
+pub use error::*;
+pub use events::*;
+pub use logs::*;
+pub use transaction::*;
@@ -0,0 +1,103 @@
+use std::str::FromStr;
+
+use anchor_lang::prelude::Pubkey;
+use base64::{engine::general_purpose::STANDARD, Engine};
+
+use crate::{
+    decode_event_data, DecodeError, LegacyNewTokenLog, LegacyReservesLog, LegacySwapLog,
+    ProgramEvent,
+};
+
+const PROGRAM_LOG: &str = "Program log: ";
+const PROGRAM_DATA: &str = "Program data: ";
+
+/// Decodes the events logged by `program_id` from a transaction's log
+/// messages, in log order. Logs of other programs, including the ones
+/// invoked by `program_id`, are ignored.
+pub fn decode_logs<S: AsRef<str>>(
+    logs: &[S],
+    program_id: &Pubkey,
+) -> Result<Vec<ProgramEvent>, DecodeError> {
+    let program_id = program_id.to_string();
+    let mut invocations: Vec<&str> = Vec::new();
+    let mut events = Vec::new();
+
+    for line in logs.iter().map(AsRef::as_ref) {
+        if let Some(rest) = line.strip_prefix("Program ") {
+            if let Some((program, tail)) = rest.split_once(' ') {
+                if tail.starts_with("invoke [") {
+                    invocations.push(program);
+                    continue;
+                }
+                if tail == "success" || tail.starts_with("failed") {
+                    invocations.pop();
+                    continue;
+                }
+            }
+        }
+
+        if invocations.last() != Some(&program_id.as_str()) {
+            continue;
+        }
+
+        if let Some(data) = line.strip_prefix(PROGRAM_DATA) {
+            if let Some(event) = decode_event_data(&STANDARD.decode(data)?)? {
+                events.push(event);
+            }
+        } else if let Some(message) = line.strip_prefix(PROGRAM_LOG) {
+            if let Some(event) = decode_legacy_log(message)? {
+                events.push(event);
+            }
+        }
+    }
+
+    Ok(events)
+}
+
+/// Parses the `msg!` lines that earlier program versions used instead of
+/// typed events.
+pub fn decode_legacy_log(message: &str) -> Result<Option<ProgramEvent>, DecodeError> {
+    if let Some(fields) = message.strip_prefix("SwapEvent: ") {
+        let malformed = || DecodeError::MalformedLog {
+            name: "SwapEvent",
+            line: message.to_string(),
+        };
+        let [user, direction, amount_out] = split_fields(fields).ok_or_else(malformed)?;
+        return Ok(Some(ProgramEvent::LegacySwap(LegacySwapLog {
+            user: Pubkey::from_str(user).map_err(|_| malformed())?,
+            direction: direction.parse().map_err(|_| malformed())?,
+            amount_out: amount_out.parse().map_err(|_| malformed())?,
+        })));
+    }
+
+    if let Some(fields) = message.strip_prefix("NewToken: ") {
+        let malformed = || DecodeError::MalformedLog {
+            name: "NewToken",
+            line: message.to_string(),
+        };
+        let [mint, creator] = split_fields(fields).ok_or_else(malformed)?;
+        return Ok(Some(ProgramEvent::LegacyNewToken(LegacyNewTokenLog {
+            mint: Pubkey::from_str(mint).map_err(|_| malformed())?,
+            creator: Pubkey::from_str(creator).map_err(|_| malformed())?,
+        })));
+    }
+
+    if let Some(fields) = message.strip_prefix("Reserves: ") {
+        let malformed = || DecodeError::MalformedLog {
+            name: "Reserves",
+            line: message.to_string(),
+        };
+        let [reserve_token, reserve_lamport] = split_fields(fields).ok_or_else(malformed)?;
+        return Ok(Some(ProgramEvent::LegacyReserves(LegacyReservesLog {
+            reserve_token: reserve_token.parse().map_err(|_| malformed())?,
+            reserve_lamport: reserve_lamport.parse().map_err(|_| malformed())?,
+        })));
+    }
+
+    Ok(None)
+}
+
+fn split_fields<const N: usize>(fields: &str) -> Option<[&str; N]> {
+    let fields: Vec<&str> = fields.split_whitespace().collect();
+    fields.try_into().ok()
+}
@@ -0,0 +1,152 @@
+use anchor_lang::prelude::Pubkey;
+use serde::Deserialize;
+
+use crate::{decode_cpi_event, decode_logs, DecodeError, ProgramEvent};
+
+/// The parts of a `getTransaction` response needed to decode events. Both the
+/// `json` and `jsonParsed` encodings are accepted.
+#[derive(Debug, Clone, Deserialize)]
+pub struct EncodedTransaction {
+    pub meta: Option<TransactionMeta>,
+    pub transaction: TransactionEnvelope,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionMeta {
+    #[serde(default)]
+    pub log_messages: Option<Vec<String>>,
+    #[serde(default)]
+    pub inner_instructions: Option<Vec<InnerInstructions>>,
+    #[serde(default)]
+    pub loaded_addresses: Option<LoadedAddresses>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct InnerInstructions {
+    pub index: u8,
+    pub instructions: Vec<InnerInstruction>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum InnerInstruction {
+    #[serde(rename_all = "camelCase")]
+    Compiled { program_id_index: usize, data: String },
+    #[serde(rename_all = "camelCase")]
+    PartiallyDecoded { program_id: String, data: String },
+    Parsed(serde_json::Value),
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct LoadedAddresses {
+    #[serde(default)]
+    pub writable: Vec<String>,
+    #[serde(default)]
+    pub readonly: Vec<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct TransactionEnvelope {
+    pub message: TransactionMessage,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionMessage {
+    pub account_keys: Vec<AccountKey>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum AccountKey {
+    Plain(String),
+    Parsed { pubkey: String },
+}
+
+impl AccountKey {
+    fn as_str(&self) -> &str {
+        match self {
+            Self::Plain(key) | Self::Parsed { pubkey: key } => key,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RpcResponse {
+    Envelope { result: EncodedTransaction },
+    Transaction(EncodedTransaction),
+}
+
+impl EncodedTransaction {
+    /// Parses a `getTransaction` result, with or without the JSON-RPC envelope.
+    pub fn from_json(json: &str) -> Result<Self, DecodeError> {
+        Ok(match serde_json::from_str(json)? {
+            RpcResponse::Envelope { result } => result,
+            RpcResponse::Transaction(transaction) => transaction,
+        })
+    }
+
+    /// Static account keys followed by the addresses loaded from lookup tables,
+    /// which is the order `programIdIndex` refers to.
+    fn account_keys(&self) -> Vec<&str> {
+        let loaded = self.meta.as_ref().and_then(|meta| meta.loaded_addresses.as_ref());
+        self.transaction
+            .message
+            .account_keys
+            .iter()
+            .map(AccountKey::as_str)
+            .chain(loaded.into_iter().flat_map(|loaded| {
+                loaded.writable.iter().chain(loaded.readonly.iter()).map(String::as_str)
+            }))
+            .collect()
+    }
+
+    /// Returns the events emitted by `program_id`: first the ones found in the
+    /// logs, then the `emit_cpi!` events found in the inner instructions.
+    pub fn decode_events(&self, program_id: &Pubkey) -> Result<Vec<ProgramEvent>, DecodeError> {
+        let Some(meta) = &self.meta else {
+            return Ok(Vec::new());
+        };
+
+        let mut events = decode_logs(meta.log_messages.as_deref().unwrap_or_default(), program_id)?;
+
+        let account_keys = self.account_keys();
+        let program_id = program_id.to_string();
+        for instruction in meta.inner_instructions.iter().flatten().flat_map(|set| &set.instructions) {
+            let (invoked_program, data) = match instruction {
+                InnerInstruction::Compiled { program_id_index, data } => (
+                    *account_keys
+                        .get(*program_id_index)
+                        .ok_or(DecodeError::UnknownAccountIndex(*program_id_index))?,
+                    data,
+                ),
+                InnerInstruction::PartiallyDecoded { program_id, data } => (program_id.as_str(), data),
+                InnerInstruction::Parsed(_) => continue,
+            };
+
+            if invoked_program != program_id {
+                continue;
+            }
+            if let Some(event) = decode_cpi_event(&bs58::decode(data).into_vec()?)? {
+                events.push(event);
+            }
+        }
+
+        Ok(events)
+    }
+}
+
+/// Decodes the events of `program_id` from a `getTransaction` JSON response.
+pub fn decode_transaction_json(
+    json: &str,
+    program_id: &Pubkey,
+) -> Result<Vec<ProgramEvent>, DecodeError> {
+    EncodedTransaction::from_json(json)?.decode_events(program_id)
+}
+
+/// Same as [`decode_transaction_json`] for the deployed ApeOrDie program.
+pub fn decode_ape_or_die_transaction(json: &str) -> Result<Vec<ProgramEvent>, DecodeError> {
+    decode_transaction_json(json, &ape_or_die::ID)
+}
@@ -0,0 +1,115 @@
+use std::str::FromStr;
+
+use anchor_lang::prelude::Pubkey;
+use ape_or_die::events::{CompleteEvent, SwapEvent};
+use ape_or_die_event_decoder::*;
+
+const LEGACY_SWAP_COMPLETE: &str = include_str!("fixtures/legacy_swap_complete.json");
+const LEGACY_LAUNCH: &str = include_str!("fixtures/legacy_launch.json");
+const CPI_SWAP_COMPLETE: &str = include_str!("fixtures/cpi_swap_complete.json");
+
+fn user() -> Pubkey {
+    Pubkey::from_str("LQVcTQajEfHFgC7dJeWJ6R3uBsqZrSdp9rTzv344p4A").unwrap()
+}
+
+fn mint() -> Pubkey {
+    Pubkey::from_str("FqUwnBMN1shpeqKVm7W5fN73tvrjVr19TQFFgkoFFzhq").unwrap()
+}
+
+fn bonding_curve() -> Pubkey {
+    Pubkey::from_str("9fbt64APipnCQGKyY5PjZYfKw4HkAnESEmupLZhuUiwG").unwrap()
+}
+
+fn creator() -> Pubkey {
+    Pubkey::from_str("DgX9xEoN7RZGWevFVCy13JuzKsnmAx9B3VLfvoJxwqKn").unwrap()
+}
+
+fn complete_event() -> CompleteEvent {
+    CompleteEvent {
+        user: user(),
+        mint: mint(),
+        bonding_curve: bonding_curve(),
+    }
+}
+
+#[test]
+fn decodes_legacy_swap_logs_and_program_data() {
+    let events = decode_ape_or_die_transaction(LEGACY_SWAP_COMPLETE).unwrap();
+
+    assert_eq!(
+        events,
+        vec![
+            ProgramEvent::Complete(complete_event()),
+            ProgramEvent::LegacyReserves(LegacyReservesLog {
+                reserve_token: 206_820_754_716_982,
+                reserve_lamport: 84_999_000_000,
+            }),
+            ProgramEvent::LegacySwap(LegacySwapLog {
+                user: user(),
+                direction: 0,
+                amount_out: 1_179_245_283_018,
+            }),
+        ]
+    );
+}
+
+#[test]
+fn decodes_legacy_launch_log() {
+    let events = decode_ape_or_die_transaction(LEGACY_LAUNCH).unwrap();
+
+    assert_eq!(
+        events,
+        vec![ProgramEvent::LegacyNewToken(LegacyNewTokenLog {
+            mint: mint(),
+            creator: creator(),
+        })]
+    );
+}
+
+#[test]
+fn decodes_cpi_events_through_lookup_table_program_id() {
+    let events = decode_ape_or_die_transaction(CPI_SWAP_COMPLETE).unwrap();
+
+    assert_eq!(
+        events,
+        vec![
+            ProgramEvent::Swap(SwapEvent {
+                user: user(),
+                mint: mint(),
+                bonding_curve: bonding_curve(),
+                direction: 0,
+                amount_in: 100_000_000,
+                amount_out: 1_179_245_283_018,
+                platform_fee: 500_000,
+                dev_fee: 500_000,
+                reserve_token: 206_820_754_716_982,
+                reserve_lamport: 84_999_000_000,
+                timestamp: 1_733_000_100,
+            }),
+            ProgramEvent::Complete(complete_event()),
+        ]
+    );
+}
+
+#[test]
+fn ignores_logs_of_other_programs() {
+    let other_program = Pubkey::new_unique();
+    let events = decode_transaction_json(LEGACY_SWAP_COMPLETE, &other_program).unwrap();
+
+    assert!(events.is_empty());
+}
+
+#[test]
+fn rejects_malformed_legacy_log() {
+    let program_id = ape_or_die::ID.to_string();
+    let logs = [
+        format!("Program {program_id} invoke [1]"),
+        "Program log: Reserves: 100".to_string(),
+        format!("Program {program_id} success"),
+    ];
+
+    assert!(matches!(
+        decode_logs(&logs, &ape_or_die::ID),
+        Err(DecodeError::MalformedLog { name: "Reserves", .. })
+    ));
+}
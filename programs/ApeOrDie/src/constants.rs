@@ -1,5 +1,31 @@
 pub const CONFIG: &str = "config";
 pub const GLOBAL: &str = "global";
 pub const BONDING_CURVE: &str = "bonding_curve";
+pub const CURVE_VAULT: &str = "curve_vault";
 pub const METADATA: &str = "metadata";
+pub const MINT: &str = "mint";
 pub const LAMPORT_DECIMALS: u8 = 9;
+pub const PRESALE_RECEIPT: &str = "presale_receipt";
+pub const MAX_PRESALE_DURATION: i64 = 7 * 24 * 60 * 60;
+pub const CANDLES: &str = "candles";
+pub const CREATOR_REGISTRY: &str = "creator_registry";
+pub const VESTING_ESCROW: &str = "vesting_escrow";
+pub const MAX_VESTING_PERIOD: i64 = 4 * 365 * 24 * 60 * 60;
+//  each allocation adds a recipient and its ata to the launch accounts,
+//  launches with allocations need a v0 transaction with an address lookup table
+pub const MAX_LAUNCH_ALLOCATIONS: usize = 8;
+pub const CANDLE_COUNT: usize = 200;
+pub const CANDLE_INTERVAL: i64 = 60;
+pub const PENDING_CONFIG_CHANGE: &str = "pending_config_change";
+pub const MAX_CONFIG_CHANGE_DELAY: i64 = 30 * 24 * 60 * 60;
+pub const MAX_NOMINATION_VALIDITY: i64 = 30 * 24 * 60 * 60;
+pub const DEFAULT_NOMINATION_VALIDITY: i64 = 7 * 24 * 60 * 60;
+//  token metadata program limits, in bytes
+pub const MAX_NAME_LENGTH: usize = 32;
+pub const MAX_SYMBOL_LENGTH: usize = 10;
+pub const MAX_URI_LENGTH: usize = 200;
+pub const MAX_LAUNCH_FEE_LAMPORTS: u64 = 10_000_000_000;
+pub const MAX_LAUNCH_FEE_DISCOUNTS: usize = 64;
+//  launch timestamps kept per creator for rate limiting
+pub const RECENT_LAUNCH_COUNT: usize = 16;
+pub const MAX_LAUNCH_RATE_WINDOW: i64 = 30 * 24 * 60 * 60;
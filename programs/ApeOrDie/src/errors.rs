@@ -48,4 +48,163 @@ pub enum PumpfunError {
 
     #[msg("Decimal overflow")]
     DecimalOverflow,
+
+    #[msg("Presale settings are invalid")]
+    InvalidPresaleConfig,
+
+    #[msg("Presale receipt account is required during the presale")]
+    PresaleReceiptRequired,
+
+    #[msg("Buyer is not on the presale allowlist")]
+    NotOnPresaleAllowlist,
+
+    #[msg("Presale wallet cap exceeded")]
+    PresaleCapExceeded,
+
+    #[msg("Curve still uses the global vault, migrate it first")]
+    CurveVaultNotMigrated,
+
+    #[msg("Curve already uses its own vault")]
+    CurveVaultAlreadyMigrated,
+
+    #[msg("Remaining accounts are invalid")]
+    InvalidRemainingAccounts,
+
+    #[msg("Vault account does not belong to the curve")]
+    IncorrectVaultAccount,
+
+    #[msg("Account passed more than once")]
+    DuplicateAccount,
+
+    #[msg("Platform fee can not exceed 10000 bps")]
+    PlatformFeeTooHigh,
+
+    #[msg("Trading fee and dev fee share can not exceed 10000 bps together")]
+    FeeSplitTooHigh,
+
+    #[msg("Bonding curve init percentage must be between 0 and 100")]
+    InvalidInitBondingCurve,
+
+    #[msg("Curve limit must exceed the maximum allowed virtual lamport reserves")]
+    CurveLimitTooLow,
+
+    #[msg("Amount config enum has no options")]
+    EmptyAmountConfig,
+
+    #[msg("Amount config range minimum is above its maximum")]
+    InvalidAmountRange,
+
+    #[msg("Dev fees are enabled without a dev wallet")]
+    DevWalletNotSet,
+
+    #[msg("Config change delay is out of range")]
+    InvalidConfigChangeDelay,
+
+    #[msg("Fees, fee wallets and the change delay have to go through queue_config_change")]
+    TimelockedConfigChange,
+
+    #[msg("Config change can not be executed before its eta")]
+    ConfigChangeNotReady,
+
+    #[msg("Signer does not hold the required role")]
+    MissingRole,
+
+    #[msg("Launches and swaps are paused")]
+    ProgramPaused,
+
+    #[msg("Nomination validity is out of range")]
+    InvalidNominationValidity,
+
+    #[msg("Nomination has expired")]
+    NominationExpired,
+
+    #[msg("There is no pending nomination")]
+    NoPendingNomination,
+
+    #[msg("Config authority has been renounced")]
+    AuthorityRenounced,
+
+    #[msg("Creating a config tier requires the root config")]
+    RootConfigRequired,
+
+    #[msg("Bonding curve belongs to another config tier")]
+    ConfigTierMismatch,
+
+    #[msg("Value is not on the configured step")]
+    ValueNotOnStep,
+
+    #[msg("Amount config step must be above zero")]
+    InvalidAmountStep,
+
+    #[msg("Metadata policy limits are out of range")]
+    InvalidMetadataPolicy,
+
+    #[msg("Token name is too long")]
+    NameTooLong,
+
+    #[msg("Token symbol is too long")]
+    SymbolTooLong,
+
+    #[msg("Token uri is too long")]
+    UriTooLong,
+
+    #[msg("Token uri does not start with an allowed prefix")]
+    UriSchemeNotAllowed,
+
+    #[msg("Token symbol has a character outside the allowed charset")]
+    InvalidSymbolCharacter,
+
+    #[msg("Signer is not the creator of the curve")]
+    NotCurveCreator,
+
+    #[msg("Mint account is not the pda of the creator and nonce")]
+    IncorrectMintAccount,
+
+    #[msg("Mint keypair has to sign when no mint nonce is given")]
+    MintSignatureRequired,
+
+    #[msg("Launch fee is too high")]
+    LaunchFeeTooHigh,
+
+    #[msg("Launch fee discounts are invalid, too many, above 10000 bps or duplicated")]
+    InvalidLaunchFeeDiscounts,
+
+    #[msg("Launch rate limit is out of range")]
+    InvalidLaunchRateLimit,
+
+    #[msg("Creator reached the launch limit of the current window")]
+    LaunchRateLimited,
+
+    #[msg("Vesting cliff or duration is out of range")]
+    InvalidVestingSchedule,
+
+    #[msg("No vested tokens to claim")]
+    NothingToClaim,
+
+    #[msg("Allocation limits are out of range")]
+    InvalidAllocationLimits,
+
+    #[msg("Too many launch allocations")]
+    TooManyAllocations,
+
+    #[msg("Launch allocation is empty or above the per-recipient cap")]
+    AllocationTooLarge,
+
+    #[msg("Allocations leave the curve below its minimum share")]
+    CurveShareTooLow,
+
+    #[msg("No accrued fees to sweep")]
+    NoFeesToSweep,
+
+    #[msg("Config already has the current layout")]
+    ConfigAlreadyMigrated,
+
+    #[msg("A migration operator has to be assigned first")]
+    MigrationOperatorRequired,
+
+    #[msg("Lowering or removing a launch fee discount has to be queued")]
+    LaunchFeeDiscountReduced,
+
+    #[msg("Candle account is required for curves with price history")]
+    CurveCandlesRequired,
 }
@@ -1,8 +1,274 @@
+use crate::state::{
+    Config, ConfigChange, CurveParams, GraduationMetadata, LaunchAllocation, LaunchFeeDiscount,
+    LaunchLimits, MetadataPolicy, Role,
+};
 use anchor_lang::prelude::*;
 
 #[event]
+#[derive(Debug, Clone, PartialEq)]
 pub struct CompleteEvent {
     pub user: Pubkey,
     pub mint: Pubkey,
     pub bonding_curve: Pubkey,
-}
\ No newline at end of file
+}
+
+#[event]
+#[derive(Debug, Clone, PartialEq)]
+pub struct LaunchEvent {
+    pub creator: Pubkey,
+    pub mint: Pubkey,
+    pub bonding_curve: Pubkey,
+    pub decimals: u8,
+    pub token_supply: u64,
+    pub curve_token_amount: u64,
+    pub team_token_amount: u64,
+    pub virtual_lamport_reserves: u64,
+    pub curve_limit: u64,
+    pub presale_end_ts: i64,
+    pub config_tier: u8,
+    pub launch_fee: u64,
+    pub allocations: Vec<LaunchAllocation>,
+    pub allocated_token_amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Debug, Clone, PartialEq)]
+pub struct MetadataUpdatedEvent {
+    pub creator: Pubkey,
+    pub mint: Pubkey,
+    pub bonding_curve: Pubkey,
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Debug, Clone, PartialEq)]
+pub struct VestedClaimedEvent {
+    pub team_wallet: Pubkey,
+    pub mint: Pubkey,
+    pub bonding_curve: Pubkey,
+    pub amount: u64,
+    pub claimed_amount: u64,
+    pub total_amount: u64,
+    pub timestamp: i64,
+}
+
+//  direction - 0: buy, 1: sell
+//  fees are in lamports, reserves are after the swap
+#[event]
+#[derive(Debug, Clone, PartialEq)]
+pub struct SwapEvent {
+    pub user: Pubkey,
+    pub mint: Pubkey,
+    pub bonding_curve: Pubkey,
+    pub direction: u8,
+    pub amount_in: u64,
+    pub amount_out: u64,
+    pub platform_fee: u64,
+    pub dev_fee: u64,
+    pub reserve_token: u64,
+    pub reserve_lamport: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Debug, Clone, PartialEq)]
+pub struct WithdrawEvent {
+    pub admin: Pubkey,
+    pub mint: Pubkey,
+    pub bonding_curve: Pubkey,
+    pub lamport_amount: u64,
+    pub token_amount: u64,
+    pub graduation_metadata: GraduationMetadata,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Debug, Clone, PartialEq)]
+pub struct FeesSweptEvent {
+    pub mint: Pubkey,
+    pub bonding_curve: Pubkey,
+    pub team_wallet: Pubkey,
+    pub platform_fee: u64,
+    pub dev_fee: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfigMigratedEvent {
+    pub payer: Pubkey,
+    pub config: Config,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Debug, Clone, PartialEq)]
+pub struct CurveVaultMigratedEvent {
+    pub mint: Pubkey,
+    pub bonding_curve: Pubkey,
+    pub curve_vault: Pubkey,
+    pub lamport_amount: u64,
+    pub token_amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfigUpdatedEvent {
+    pub authority: Pubkey,
+    pub config: Config,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfigChangeQueuedEvent {
+    pub authority: Pubkey,
+    pub change: ConfigChange,
+    pub eta: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfigChangeExecutedEvent {
+    pub authority: Pubkey,
+    pub old: ConfigChange,
+    pub new: ConfigChange,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfigChangeCancelledEvent {
+    pub authority: Pubkey,
+    pub change: ConfigChange,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Debug, Clone, PartialEq)]
+pub struct CurveParamsUpdatedEvent {
+    pub authority: Pubkey,
+    pub old: CurveParams,
+    pub new: CurveParams,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Debug, Clone, PartialEq)]
+pub struct LaunchLimitsUpdatedEvent {
+    pub authority: Pubkey,
+    pub old: LaunchLimits,
+    pub new: LaunchLimits,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Debug, Clone, PartialEq)]
+pub struct MetadataPolicyUpdatedEvent {
+    pub authority: Pubkey,
+    pub old: MetadataPolicy,
+    pub new: MetadataPolicy,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Debug, Clone, PartialEq)]
+pub struct LaunchFeeDiscountsUpdatedEvent {
+    pub authority: Pubkey,
+    pub old: Vec<LaunchFeeDiscount>,
+    pub new: Vec<LaunchFeeDiscount>,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Debug, Clone, PartialEq)]
+pub struct AuthorityNominatedEvent {
+    pub authority: Pubkey,
+    pub pending_authority: Pubkey,
+    pub expires_at: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Debug, Clone, PartialEq)]
+pub struct AuthorityAcceptedEvent {
+    pub previous_authority: Pubkey,
+    pub new_authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Debug, Clone, PartialEq)]
+pub struct AuthorityRenouncedEvent {
+    pub previous_authority: Pubkey,
+    pub timestamp: i64,
+}
+
+//  `role` is None for the authority nomination
+#[event]
+#[derive(Debug, Clone, PartialEq)]
+pub struct NominationCancelledEvent {
+    pub role: Option<Role>,
+    pub authority: Pubkey,
+    pub cancelled_nominee: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Debug, Clone, PartialEq)]
+pub struct RoleNominatedEvent {
+    pub role: Role,
+    pub authority: Pubkey,
+    pub pending_holder: Pubkey,
+    pub expires_at: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Debug, Clone, PartialEq)]
+pub struct RoleAcceptedEvent {
+    pub role: Role,
+    pub previous_holder: Pubkey,
+    pub new_holder: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Debug, Clone, PartialEq)]
+pub struct RoleRevokedEvent {
+    pub role: Role,
+    pub authority: Pubkey,
+    pub previous_holder: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PausedEvent {
+    pub guardian: Pubkey,
+    pub paused: bool,
+    pub timestamp: i64,
+}
+
+//  proof-of-reserves report for a set of curves
+#[event]
+#[derive(Debug, Clone, PartialEq)]
+pub struct AuditEvent {
+    pub curves: u32,
+    pub sol_obligation: u64,
+    pub sol_balance: u64,
+    pub rent_reserve: u64,
+    pub sol_surplus: u64,
+    pub sol_shortfall: u64,
+    pub token_obligation: u64,
+    pub token_balance: u64,
+    pub token_surplus: u64,
+    pub token_shortfall: u64,
+    pub timestamp: i64,
+}
@@ -1,8 +1,10 @@
 use constants::CONFIG;
 use errors::PumpfunError;
+use events::AuthorityAcceptedEvent;
 
 use crate::*;
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct AcceptAuthority<'info> {
     //  Pending admin
@@ -15,17 +17,29 @@ pub struct AcceptAuthority<'info> {
     //  Stores admin address
     #[account(
         mut,
-        seeds = [CONFIG.as_bytes()],
+        seeds = [CONFIG.as_bytes(), Config::tier_seed(&global_config.tier_id)],
         bump,
     )]
     global_config: Box<Account<'info, Config>>,
 }
 
 impl AcceptAuthority<'_> {
-    pub fn process(&mut self) -> Result<()> {
+    pub fn process(&mut self) -> Result<AuthorityAcceptedEvent> {
+        let timestamp = Clock::get()?.unix_timestamp;
+        let expires_at = self
+            .global_config
+            .nomination_expiry(self.global_config.pending_authority_nominated_at);
+        require!(timestamp <= expires_at, PumpfunError::NominationExpired);
+
+        let previous_authority = self.global_config.authority;
         self.global_config.authority = self.new_admin.key();
         self.global_config.pending_authority = Pubkey::default();
+        self.global_config.pending_authority_nominated_at = 0;
 
-        Ok(())
+        Ok(AuthorityAcceptedEvent {
+            previous_authority,
+            new_authority: self.new_admin.key(),
+            timestamp,
+        })
     }
 }
@@ -0,0 +1,49 @@
+use constants::CONFIG;
+use errors::PumpfunError;
+use events::RoleAcceptedEvent;
+use state::Role;
+
+use crate::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(role: Role)]
+pub struct AcceptRole<'info> {
+    //  Pending role holder
+    #[account(
+        mut,
+        constraint = global_config.role(role).pending == new_holder.key() @PumpfunError::MissingRole
+    )]
+    pub new_holder: Signer<'info>,
+
+    //  Stores role holders
+    #[account(
+        mut,
+        seeds = [CONFIG.as_bytes(), Config::tier_seed(&global_config.tier_id)],
+        bump,
+    )]
+    global_config: Box<Account<'info, Config>>,
+}
+
+impl AcceptRole<'_> {
+    pub fn process(&mut self, role: Role) -> Result<RoleAcceptedEvent> {
+        let timestamp = Clock::get()?.unix_timestamp;
+        let expires_at = self
+            .global_config
+            .nomination_expiry(self.global_config.role(role).nominated_at);
+        require!(timestamp <= expires_at, PumpfunError::NominationExpired);
+
+        let assignment = self.global_config.role_mut(role);
+        let previous_holder = assignment.holder;
+        assignment.holder = self.new_holder.key();
+        assignment.pending = Pubkey::default();
+        assignment.nominated_at = 0;
+
+        Ok(RoleAcceptedEvent {
+            role,
+            previous_holder,
+            new_holder: self.new_holder.key(),
+            timestamp,
+        })
+    }
+}
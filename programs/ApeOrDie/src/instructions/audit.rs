@@ -0,0 +1,137 @@
+use crate::{
+    constants::{CURVE_VAULT, GLOBAL},
+    errors::*,
+    events::AuditEvent,
+    state::BondingCurve,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, TokenAccount};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct Audit<'info> {
+    /// CHECK: global vault pda which stores SOL of legacy curves
+    #[account(
+        seeds = [GLOBAL.as_bytes()],
+        bump,
+    )]
+    pub global_vault: AccountInfo<'info>,
+}
+
+impl<'info> Audit<'info> {
+    //  remaining accounts are passed in groups of three per curve:
+    //  [bonding_curve, vault, vault_ata]
+    //  the vault is the curve vault, or the global vault for curves that were not migrated yet
+    pub fn process(&self, remaining_accounts: &[AccountInfo<'info>]) -> Result<AuditEvent> {
+        require!(
+            !remaining_accounts.is_empty()
+                && remaining_accounts
+                    .chunks(3)
+                    .all(|accounts| accounts.len() == 3),
+            PumpfunError::InvalidRemainingAccounts
+        );
+
+        let rent = Rent::get()?;
+
+        let mut seen_curves: Vec<Pubkey> = Vec::new();
+        let mut seen_vaults: Vec<Pubkey> = Vec::new();
+
+        let mut sol_obligation: u64 = 0;
+        let mut sol_balance: u64 = 0;
+        let mut rent_reserve: u64 = 0;
+        let mut token_obligation: u64 = 0;
+        let mut token_balance: u64 = 0;
+
+        for accounts in remaining_accounts.chunks(3) {
+            let (curve_info, vault_info, vault_ata_info) =
+                (&accounts[0], &accounts[1], &accounts[2]);
+
+            require!(
+                curve_info.owner == &crate::ID,
+                PumpfunError::InvalidRemainingAccounts
+            );
+            require!(
+                !seen_curves.contains(curve_info.key),
+                PumpfunError::DuplicateAccount
+            );
+            seen_curves.push(curve_info.key());
+
+            let bonding_curve =
+                BondingCurve::try_deserialize_padded(&curve_info.try_borrow_data()?)?;
+
+            //  make sure the vault is the one holding this curve's funds
+            let expected_vault = if bonding_curve.has_curve_vault {
+                Pubkey::create_program_address(
+                    &[
+                        CURVE_VAULT.as_bytes(),
+                        curve_info.key.as_ref(),
+                        &[bonding_curve.curve_vault_bump],
+                    ],
+                    &crate::ID,
+                )
+                .map_err(|_| PumpfunError::IncorrectVaultAccount)?
+            } else {
+                self.global_vault.key()
+            };
+            require!(
+                vault_info.key() == expected_vault,
+                PumpfunError::IncorrectVaultAccount
+            );
+
+            require!(
+                vault_ata_info.owner == &token::ID,
+                PumpfunError::IncorrectVaultAccount
+            );
+            let vault_ata =
+                TokenAccount::try_deserialize(&mut &**vault_ata_info.try_borrow_data()?)?;
+            require!(
+                vault_ata.owner == expected_vault && vault_ata.mint == bonding_curve.token_mint,
+                PumpfunError::IncorrectVaultAccount
+            );
+
+            //  legacy curves share the global vault, count its balance once
+            if !seen_vaults.contains(vault_info.key) {
+                seen_vaults.push(vault_info.key());
+                sol_balance = sol_balance
+                    .checked_add(vault_info.lamports())
+                    .ok_or(PumpfunError::OverflowOrUnderflowOccurred)?;
+                rent_reserve = rent_reserve
+                    .checked_add(rent.minimum_balance(vault_info.data_len()))
+                    .ok_or(PumpfunError::OverflowOrUnderflowOccurred)?;
+            }
+
+            //  virtual reserves are not backed by SOL, accrued fees are owed to the team & dev wallets
+            sol_obligation = sol_obligation
+                .checked_add(
+                    bonding_curve
+                        .reserve_lamport
+                        .saturating_sub(bonding_curve.init_lamport),
+                )
+                .and_then(|total| total.checked_add(bonding_curve.accrued_platform_fee))
+                .and_then(|total| total.checked_add(bonding_curve.accrued_dev_fee))
+                .ok_or(PumpfunError::OverflowOrUnderflowOccurred)?;
+            token_obligation = token_obligation
+                .checked_add(bonding_curve.reserve_token)
+                .ok_or(PumpfunError::OverflowOrUnderflowOccurred)?;
+            token_balance = token_balance
+                .checked_add(vault_ata.amount)
+                .ok_or(PumpfunError::OverflowOrUnderflowOccurred)?;
+        }
+
+        let sol_available = sol_balance.saturating_sub(rent_reserve);
+
+        Ok(AuditEvent {
+            curves: seen_curves.len() as u32,
+            sol_obligation,
+            sol_balance,
+            rent_reserve,
+            sol_surplus: sol_available.saturating_sub(sol_obligation),
+            sol_shortfall: sol_obligation.saturating_sub(sol_available),
+            token_obligation,
+            token_balance,
+            token_surplus: token_balance.saturating_sub(token_obligation),
+            token_shortfall: token_obligation.saturating_sub(token_balance),
+            timestamp: Clock::get()?.unix_timestamp,
+        })
+    }
+}
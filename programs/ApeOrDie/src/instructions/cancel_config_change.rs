@@ -0,0 +1,45 @@
+use crate::{
+    constants::{CONFIG, PENDING_CONFIG_CHANGE},
+    errors::*,
+    events::ConfigChangeCancelledEvent,
+    state::{Config, PendingConfigChange},
+};
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CancelConfigChange<'info> {
+    //  authority, or the fee admin for fee changes
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG.as_bytes(), Config::tier_seed(&global_config.tier_id)],
+        bump,
+    )]
+    global_config: Box<Account<'info, Config>>,
+
+    #[account(
+        mut,
+        close = admin,
+        seeds = [PENDING_CONFIG_CHANGE.as_bytes(), global_config.key().as_ref()],
+        bump
+    )]
+    pending_config_change: Box<Account<'info, PendingConfigChange>>,
+}
+
+impl CancelConfigChange<'_> {
+    pub fn process(&mut self) -> Result<ConfigChangeCancelledEvent> {
+        require!(
+            self.global_config
+                .can_change(&self.pending_config_change.change, self.admin.key),
+            PumpfunError::MissingRole
+        );
+
+        Ok(ConfigChangeCancelledEvent {
+            authority: self.admin.key(),
+            change: self.pending_config_change.change.clone(),
+            timestamp: Clock::get()?.unix_timestamp,
+        })
+    }
+}
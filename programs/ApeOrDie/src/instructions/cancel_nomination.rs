@@ -0,0 +1,57 @@
+use constants::CONFIG;
+use errors::PumpfunError;
+use events::NominationCancelledEvent;
+use state::Role;
+
+use crate::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CancelNomination<'info> {
+    // Current admin
+    #[account(
+        mut,
+        constraint = global_config.authority == *admin.key @PumpfunError::IncorrectAuthority
+    )]
+    pub admin: Signer<'info>,
+
+    //  Stores admin address
+    #[account(
+        mut,
+        seeds = [CONFIG.as_bytes(), Config::tier_seed(&global_config.tier_id)],
+        bump,
+    )]
+    global_config: Box<Account<'info, Config>>,
+}
+
+impl CancelNomination<'_> {
+    //  role None cancels the authority nomination
+    pub fn process(&mut self, role: Option<Role>) -> Result<NominationCancelledEvent> {
+        let cancelled_nominee = match role {
+            Some(role) => {
+                let assignment = self.global_config.role_mut(role);
+                let pending = assignment.pending;
+                assignment.pending = Pubkey::default();
+                assignment.nominated_at = 0;
+                pending
+            }
+            None => {
+                let pending = self.global_config.pending_authority;
+                self.global_config.pending_authority = Pubkey::default();
+                self.global_config.pending_authority_nominated_at = 0;
+                pending
+            }
+        };
+        require!(
+            cancelled_nominee != Pubkey::default(),
+            PumpfunError::NoPendingNomination
+        );
+
+        Ok(NominationCancelledEvent {
+            role,
+            authority: self.admin.key(),
+            cancelled_nominee,
+            timestamp: Clock::get()?.unix_timestamp,
+        })
+    }
+}
@@ -0,0 +1,132 @@
+use crate::{
+    constants::{BONDING_CURVE, CONFIG, VESTING_ESCROW},
+    errors::*,
+    events::VestedClaimedEvent,
+    state::{BondingCurve, Config, VestingEscrow},
+    utils::token_transfer_with_signer,
+};
+use anchor_lang::{prelude::*, system_program};
+use anchor_spl::{
+    associated_token::{self, AssociatedToken},
+    token::{self, Mint, Token},
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ClaimVested<'info> {
+    #[account(
+        seeds = [CONFIG.as_bytes(), Config::tier_seed(&global_config.tier_id)],
+        bump,
+    )]
+    global_config: Box<Account<'info, Config>>,
+
+    #[account(
+        mut,
+        constraint = global_config.team_wallet == team_wallet.key() @PumpfunError::IncorrectTeamWallet
+    )]
+    pub team_wallet: Signer<'info>,
+
+    token_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        seeds = [BONDING_CURVE.as_bytes(), &token_mint.key().to_bytes()],
+        bump,
+        constraint = bonding_curve.config_tier == global_config.tier_id @PumpfunError::ConfigTierMismatch
+    )]
+    bonding_curve: Box<Account<'info, BondingCurve>>,
+
+    #[account(
+        mut,
+        seeds = [VESTING_ESCROW.as_bytes(), bonding_curve.key().as_ref()],
+        bump = vesting_escrow.bump,
+    )]
+    vesting_escrow: Box<Account<'info, VestingEscrow>>,
+
+    /// CHECK: ata of vesting escrow
+    #[account(
+        mut,
+        seeds = [
+            vesting_escrow.key().as_ref(),
+            anchor_spl::token::spl_token::ID.as_ref(),
+            token_mint.key().as_ref(),
+        ],
+        bump,
+        seeds::program = anchor_spl::associated_token::ID
+    )]
+    vesting_escrow_ata: AccountInfo<'info>,
+
+    /// CHECK: ata of team wallet
+    #[account(
+        mut,
+        seeds = [
+            team_wallet.key().as_ref(),
+            anchor_spl::token::spl_token::ID.as_ref(),
+            token_mint.key().as_ref(),
+        ],
+        bump,
+        seeds::program = anchor_spl::associated_token::ID
+    )]
+    team_wallet_ata: AccountInfo<'info>,
+
+    #[account(address = system_program::ID)]
+    system_program: Program<'info, System>,
+
+    #[account(address = token::ID)]
+    token_program: Program<'info, Token>,
+
+    #[account(address = associated_token::ID)]
+    associated_token_program: Program<'info, AssociatedToken>,
+}
+
+impl ClaimVested<'_> {
+    pub fn process(&mut self) -> Result<VestedClaimedEvent> {
+        let timestamp = Clock::get()?.unix_timestamp;
+        let vesting_escrow = &mut self.vesting_escrow;
+
+        let amount = vesting_escrow
+            .vested_amount(timestamp)
+            .saturating_sub(vesting_escrow.claimed_amount);
+        require!(amount > 0, PumpfunError::NothingToClaim);
+
+        //  create team wallet ata, if it doesn't exist
+        if self.team_wallet_ata.data_is_empty() {
+            associated_token::create(CpiContext::new(
+                self.associated_token_program.to_account_info(),
+                associated_token::Create {
+                    payer: self.team_wallet.to_account_info(),
+                    associated_token: self.team_wallet_ata.to_account_info(),
+                    authority: self.team_wallet.to_account_info(),
+                    mint: self.token_mint.to_account_info(),
+                    system_program: self.system_program.to_account_info(),
+                    token_program: self.token_program.to_account_info(),
+                },
+            ))?;
+        }
+
+        let bonding_curve_key = self.bonding_curve.key();
+        token_transfer_with_signer(
+            self.vesting_escrow_ata.clone(),
+            vesting_escrow.to_account_info(),
+            self.team_wallet_ata.clone(),
+            &self.token_program,
+            &[&[
+                VESTING_ESCROW.as_bytes(),
+                bonding_curve_key.as_ref(),
+                &[vesting_escrow.bump],
+            ]],
+            amount,
+        )?;
+
+        vesting_escrow.claimed_amount += amount;
+
+        Ok(VestedClaimedEvent {
+            team_wallet: self.team_wallet.key(),
+            mint: self.token_mint.key(),
+            bonding_curve: bonding_curve_key,
+            amount,
+            claimed_amount: vesting_escrow.claimed_amount,
+            total_amount: vesting_escrow.total_amount,
+            timestamp,
+        })
+    }
+}
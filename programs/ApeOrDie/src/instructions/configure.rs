@@ -1,7 +1,8 @@
 use crate::{
     constants::{CONFIG, GLOBAL},
     errors::*,
-    state::{Config, AmountConfig}, 
+    events::ConfigUpdatedEvent,
+    state::Config, 
     utils::sol_transfer_from_user,
 };
 use anchor_lang::{prelude::*, system_program, Discriminator};
@@ -11,7 +12,9 @@ use anchor_spl::{
 };
 use borsh::BorshDeserialize;
 
+#[event_cpi]
 #[derive(Accounts)]
+#[instruction(new_config: Config)]
 pub struct Configure<'info> {
     #[account(mut)]
     payer: Signer<'info>,
@@ -19,11 +22,18 @@ pub struct Configure<'info> {
     /// CHECK: initialization handled inside the instruction
     #[account(
         mut,
-        seeds = [CONFIG.as_bytes()],
+        seeds = [CONFIG.as_bytes(), Config::tier_seed(&new_config.tier_id)],
         bump,
     )]
     config: AccountInfo<'info>,
 
+    //  tier 0 config, its authority creates the other tiers
+    #[account(
+        seeds = [CONFIG.as_bytes()],
+        bump,
+    )]
+    root_config: Option<Box<Account<'info, Config>>>,
+
     /// CHECK: global vault pda which stores SOL
     #[account(
         mut,
@@ -54,30 +64,26 @@ pub struct Configure<'info> {
 }
 
 impl<'info> Configure<'info> {
-    pub fn process(&mut self, new_config: Config, config_bump: u8) -> Result<()> {
+    pub fn process(&mut self, new_config: Config, config_bump: u8) -> Result<ConfigUpdatedEvent> {
         let serialized_config =
             [&Config::DISCRIMINATOR, new_config.try_to_vec()?.as_slice()].concat();
         let serialized_config_len = serialized_config.len();
         let config_cost = Rent::get()?.minimum_balance(serialized_config_len);
 
-        // Decimal overflow check
-        match new_config.token_decimals_config {
-            AmountConfig::Range { min: _, max } => {
-                if let Some(max_val) = max {
-                    if max_val >= 20 {
-                        return err!(PumpfunError::DecimalOverflow);
-                    }
-                }
-            }
-            AmountConfig::Enum(options) => {
-                if options.iter().any(|&val| val >= 20) {
-                    return err!(PumpfunError::DecimalOverflow);
-                }
-            }
-        }
+        new_config.validate()?;
     
         // Init config pda if needed
         if self.config.owner != &crate::ID {
+            if new_config.tier_id != 0 {
+                let root_config = self
+                    .root_config
+                    .as_ref()
+                    .ok_or(PumpfunError::RootConfigRequired)?;
+                if root_config.authority != self.payer.key() {
+                    return err!(PumpfunError::IncorrectAuthority);
+                }
+            }
+
             let cpi_context = CpiContext::new(
                 self.system_program.to_account_info(),
                 system_program::CreateAccount {
@@ -87,7 +93,11 @@ impl<'info> Configure<'info> {
             );
 
             system_program::create_account(
-                cpi_context.with_signer(&[&[CONFIG.as_bytes(), &[config_bump]]]),
+                cpi_context.with_signer(&[&[
+                    CONFIG.as_bytes(),
+                    Config::tier_seed(&new_config.tier_id),
+                    &[config_bump],
+                ]]),
                 config_cost,
                 serialized_config_len as u64,
                 &crate::ID,
@@ -98,6 +108,10 @@ impl<'info> Configure<'info> {
                 return err!(PumpfunError::IncorrectConfigAccount);
             }
             let config = Config::deserialize(&mut &data[8..])?;
+
+            if config.is_renounced() {
+                return err!(PumpfunError::AuthorityRenounced);
+            }
                 
             if config.authority != self.payer.key() {
                 return err!(PumpfunError::IncorrectAuthority);
@@ -107,6 +121,32 @@ impl<'info> Configure<'info> {
             if config.authority != new_config.authority {
                 return err!(PumpfunError::IncorrectAuthority);
             }
+
+            // Nominations only change through nominate_authority / cancel_nomination
+            if config.pending_authority != new_config.pending_authority
+                || config.pending_authority_nominated_at
+                    != new_config.pending_authority_nominated_at
+            {
+                return err!(PumpfunError::IncorrectAuthority);
+            }
+
+            // Roles only change through nominate_role / accept_role / revoke_role
+            if config.roles() != new_config.roles() {
+                return err!(PumpfunError::IncorrectAuthority);
+            }
+
+            // Timelocked fields only change through queue_config_change
+            if config.fee_params() != new_config.fee_params()
+                || config.fee_wallet_params() != new_config.fee_wallet_params()
+                || config.config_change_delay != new_config.config_change_delay
+            {
+                return err!(PumpfunError::TimelockedConfigChange);
+            }
+
+            // Discounts are only lowered or removed through queue_config_change
+            if !config.keeps_launch_fee_discounts(&new_config.launch_fee_discounts) {
+                return err!(PumpfunError::LaunchFeeDiscountReduced);
+            }
         }
     
         let lamport_delta = (config_cost as i64) - (self.config.lamports() as i64);
@@ -141,6 +181,10 @@ impl<'info> Configure<'info> {
             )?;
         }
     
-        Ok(())
+        Ok(ConfigUpdatedEvent {
+            authority: new_config.authority,
+            config: new_config,
+            timestamp: Clock::get()?.unix_timestamp,
+        })
     }
 }
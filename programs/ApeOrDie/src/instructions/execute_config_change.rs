@@ -0,0 +1,59 @@
+use crate::{
+    constants::{CONFIG, PENDING_CONFIG_CHANGE},
+    errors::*,
+    events::ConfigChangeExecutedEvent,
+    state::{Config, PendingConfigChange},
+};
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ExecuteConfigChange<'info> {
+    //  authority, or the fee admin for fee changes
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [CONFIG.as_bytes(), Config::tier_seed(&global_config.tier_id)],
+        bump,
+    )]
+    global_config: Box<Account<'info, Config>>,
+
+    #[account(
+        mut,
+        close = admin,
+        seeds = [PENDING_CONFIG_CHANGE.as_bytes(), global_config.key().as_ref()],
+        bump
+    )]
+    pending_config_change: Box<Account<'info, PendingConfigChange>>,
+}
+
+impl ExecuteConfigChange<'_> {
+    pub fn process(&mut self) -> Result<ConfigChangeExecutedEvent> {
+        require!(
+            self.global_config
+                .can_change(&self.pending_config_change.change, self.admin.key),
+            PumpfunError::MissingRole
+        );
+
+        let timestamp = Clock::get()?.unix_timestamp;
+        require!(
+            timestamp >= self.pending_config_change.eta,
+            PumpfunError::ConfigChangeNotReady
+        );
+
+        let change = self.pending_config_change.change.clone();
+        let old = change.current(&self.global_config);
+        change.apply(&mut self.global_config);
+        //  other fields may have changed since the change was queued
+        self.global_config.validate()?;
+
+        Ok(ConfigChangeExecutedEvent {
+            authority: self.admin.key(),
+            old,
+            new: change,
+            timestamp,
+        })
+    }
+}
@@ -0,0 +1,45 @@
+use crate::{
+    constants::{BONDING_CURVE, CANDLES, CANDLE_INTERVAL},
+    state::{BondingCurve, CurveCandles},
+};
+use anchor_lang::{prelude::*, system_program};
+use anchor_spl::token::Mint;
+
+#[derive(Accounts)]
+pub struct InitCandles<'info> {
+    //  anyone can create the price history of a curve launched without one
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    token_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [BONDING_CURVE.as_bytes(), &token_mint.key().to_bytes()],
+        bump
+    )]
+    bonding_curve: Box<Account<'info, BondingCurve>>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + std::mem::size_of::<CurveCandles>(),
+        seeds = [CANDLES.as_bytes(), bonding_curve.key().as_ref()],
+        bump
+    )]
+    curve_candles: AccountLoader<'info, CurveCandles>,
+
+    #[account(address = system_program::ID)]
+    system_program: Program<'info, System>,
+}
+
+impl InitCandles<'_> {
+    pub fn process(&mut self) -> Result<()> {
+        let mut curve_candles = self.curve_candles.load_init()?;
+        curve_candles.bonding_curve = self.bonding_curve.key();
+        curve_candles.interval = CANDLE_INTERVAL;
+        self.bonding_curve.has_candles = true;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,42 @@
+use crate::{
+    constants::{BONDING_CURVE, CREATOR_REGISTRY},
+    state::{BondingCurve, CreatorRegistry},
+};
+use anchor_lang::{prelude::*, system_program};
+use anchor_spl::token::Mint;
+
+#[derive(Accounts)]
+pub struct InitCreatorRegistry<'info> {
+    //  anyone can create the registry of a creator who launched before registries
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    token_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        seeds = [BONDING_CURVE.as_bytes(), &token_mint.key().to_bytes()],
+        bump
+    )]
+    bonding_curve: Box<Account<'info, BondingCurve>>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + CreatorRegistry::INIT_SPACE,
+        seeds = [CREATOR_REGISTRY.as_bytes(), bonding_curve.creator.as_ref()],
+        bump
+    )]
+    creator_registry: Box<Account<'info, CreatorRegistry>>,
+
+    #[account(address = system_program::ID)]
+    system_program: Program<'info, System>,
+}
+
+impl InitCreatorRegistry<'_> {
+    //  counts start from here, earlier launches are not backfilled
+    pub fn process(&mut self) -> Result<()> {
+        self.creator_registry.creator = self.bonding_curve.creator;
+
+        Ok(())
+    }
+}
@@ -1,9 +1,17 @@
 use std::ops::{Div, Mul};
 
 use crate::{
-    constants::{BONDING_CURVE, CONFIG, GLOBAL, METADATA},
+    constants::{
+        BONDING_CURVE, CANDLES, CANDLE_INTERVAL, CONFIG, CREATOR_REGISTRY, CURVE_VAULT, GLOBAL,
+        MAX_PRESALE_DURATION, METADATA, MINT, VESTING_ESCROW,
+    },
     errors::*,
-    state::{BondingCurve, Config},
+    events::LaunchEvent,
+    state::{
+        BondingCurve, Config, CreatorRegistry, CurveCandles, LaunchAllocation, PresaleConfig,
+        VestingEscrow, FEE_BASIS_POINTS,
+    },
+    utils::{create_account_with_signer, sol_transfer_from_user},
 };
 use anchor_lang::{prelude::*, solana_program::sysvar::SysvarId, system_program};
 use anchor_spl::{
@@ -11,20 +19,19 @@ use anchor_spl::{
     metadata::{self, mpl_token_metadata::types::DataV2, Metadata},
     token::{self, spl_token::instruction::AuthorityType, Mint, Token},
 };
+#[event_cpi]
 #[derive(Accounts)]
-#[instruction(decimals: u8)]
-
 pub struct Launch<'info> {
     #[account(
         mut,
-        seeds = [CONFIG.as_bytes()],
+        seeds = [CONFIG.as_bytes(), Config::tier_seed(&global_config.tier_id)],
         bump,
+        constraint = !global_config.paused @PumpfunError::ProgramPaused
     )]
     global_config: Box<Account<'info, Config>>,
 
-    /// CHECK: global vault pda which stores SOL
+    /// CHECK: global vault pda, only signs as mint & metadata authority
     #[account(
-        mut,
         seeds = [GLOBAL.as_bytes()],
         bump,
     )]
@@ -33,22 +40,36 @@ pub struct Launch<'info> {
     #[account(mut)]
     creator: Signer<'info>,
 
+    /// CHECK: created in instruction, a keypair signer or the `[MINT, creator, nonce]` pda
+    #[account(mut)]
+    token: UncheckedAccount<'info>,
+
     #[account(
         init,
         payer = creator,
-        mint::decimals = decimals,
-        mint::authority = global_vault.key(),
+        space = 8 + BondingCurve::INIT_SPACE,
+        seeds = [BONDING_CURVE.as_bytes(), &token.key().to_bytes()],
+        bump
     )]
-    token: Box<Account<'info, Mint>>,
+    bonding_curve: Box<Account<'info, BondingCurve>>,
 
     #[account(
         init,
         payer = creator,
-        space = 8 + BondingCurve::INIT_SPACE,
-        seeds = [BONDING_CURVE.as_bytes(), &token.key().to_bytes()],
+        space = 8 + std::mem::size_of::<CurveCandles>(),
+        seeds = [CANDLES.as_bytes(), bonding_curve.key().as_ref()],
         bump
     )]
-    bonding_curve: Box<Account<'info, BondingCurve>>,
+    curve_candles: AccountLoader<'info, CurveCandles>,
+
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = 8 + CreatorRegistry::INIT_SPACE,
+        seeds = [CREATOR_REGISTRY.as_bytes(), creator.key().as_ref()],
+        bump
+    )]
+    creator_registry: Box<Account<'info, CreatorRegistry>>,
 
     /// CHECK: passed to token metadata program
     #[account(
@@ -63,18 +84,26 @@ pub struct Launch<'info> {
     )]
     token_metadata_account: UncheckedAccount<'info>,
 
+    /// CHECK: curve vault pda which stores SOL of this curve
+    #[account(
+        mut,
+        seeds = [CURVE_VAULT.as_bytes(), bonding_curve.key().as_ref()],
+        bump,
+    )]
+    pub curve_vault: AccountInfo<'info>,
+
     /// CHECK: created in instruction
     #[account(
         mut,
         seeds = [
-            global_vault.key().as_ref(),
+            curve_vault.key().as_ref(),
             token::spl_token::ID.as_ref(),
             token.key().as_ref(),
         ],
         bump,
         seeds::program = associated_token::ID
     )]
-    global_token_account: UncheckedAccount<'info>,
+    curve_vault_ata: UncheckedAccount<'info>,
 
     #[account(address = system_program::ID)]
     system_program: Program<'info, System>,
@@ -99,18 +128,28 @@ pub struct Launch<'info> {
     )]
     pub team_wallet: AccountInfo<'info>,
 
-    /// CHECK: ata of team wallet
+    //  holds the team allocation until it vests
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + VestingEscrow::INIT_SPACE,
+        seeds = [VESTING_ESCROW.as_bytes(), bonding_curve.key().as_ref()],
+        bump
+    )]
+    vesting_escrow: Box<Account<'info, VestingEscrow>>,
+
+    /// CHECK: ata of vesting escrow, created in instruction
     #[account(
         mut,
         seeds = [
-            team_wallet.key().as_ref(),
+            vesting_escrow.key().as_ref(),
             anchor_spl::token::spl_token::ID.as_ref(),
             token.key().as_ref(),
         ],
         bump,
         seeds::program = anchor_spl::associated_token::ID
     )]
-    team_wallet_ata: AccountInfo<'info>,
+    vesting_escrow_ata: AccountInfo<'info>,
 }
 #[allow(clippy::too_many_arguments)]
 impl<'info> Launch<'info> {
@@ -127,16 +166,28 @@ impl<'info> Launch<'info> {
         symbol: String,
         uri: String,
 
+        presale: Option<PresaleConfig>,
+        curve_limit: Option<u64>,
+        mint_nonce: Option<u64>,
+        allocations: Vec<LaunchAllocation>,
+        remaining_accounts: &[AccountInfo<'info>],
+
         global_vault_bump: u8,
-    ) -> Result<()> {
+        curve_vault_bump: u8,
+        vesting_escrow_bump: u8,
+    ) -> Result<LaunchEvent> {
         let global_config = &self.global_config;
         let creator = &self.creator;
         let token = &self.token;
-        let global_token_account = &self.global_token_account;
+        let curve_vault = &self.curve_vault;
+        let curve_vault_ata = &self.curve_vault_ata;
         let bonding_curve = &mut self.bonding_curve;
         let global_vault = &self.global_vault;
         let team_wallet = &mut self.team_wallet;
-        let team_wallet_ata = &self.team_wallet_ata;
+        let vesting_escrow_ata = &self.vesting_escrow_ata;
+
+        // reject bad metadata with a clear error instead of a failed metadata CPI
+        global_config.metadata_policy.check(&name, &symbol, &uri)?;
 
         // Decimal overflow check
         if decimals >= 20 {
@@ -161,11 +212,93 @@ impl<'info> Launch<'info> {
 
         global_config.token_decimals_config.validate(&decimals)?;
 
-        let init_bonding_curve = (token_supply as f64)
+        let curve_limit = match curve_limit {
+            Some(curve_limit) => {
+                global_config.curve_limit_config.validate(&curve_limit)?;
+                curve_limit
+            }
+            None => global_config.curve_limit,
+        };
+        //  the curve would complete on the first buy otherwise
+        require!(
+            curve_limit > reserve_lamport,
+            PumpfunError::CurveLimitTooLow
+        );
+
+        let curve_share = (token_supply as f64)
             .mul(global_config.init_bonding_curve)
             .div(100_f64) as u64;
 
-        let amount_to_team = token_supply - init_bonding_curve;
+        let amount_to_team = token_supply - curve_share;
+
+        //  allocations are carved out of the curve's share
+        global_config.allocation_limits.check(&allocations)?;
+        require!(
+            remaining_accounts.len() == allocations.len() * 2,
+            PumpfunError::InvalidRemainingAccounts
+        );
+        let allocation_amounts = allocations
+            .iter()
+            .map(|allocation| {
+                (token_supply as u128 * allocation.bps as u128 / FEE_BASIS_POINTS) as u64
+            })
+            .collect::<Vec<u64>>();
+        let allocated_token_amount: u64 = allocation_amounts.iter().sum();
+
+        let init_bonding_curve = curve_share
+            .checked_sub(allocated_token_amount)
+            .ok_or(PumpfunError::CurveShareTooLow)?;
+        require!(
+            init_bonding_curve as u128 * FEE_BASIS_POINTS
+                >= token_supply as u128 * global_config.allocation_limits.min_curve_bps as u128,
+            PumpfunError::CurveShareTooLow
+        );
+
+        // create the mint, at a predictable pda when the creator passed a nonce
+        let mint_space = Mint::LEN;
+        match mint_nonce {
+            Some(nonce) => {
+                let creator_key = creator.key();
+                let nonce_bytes = nonce.to_le_bytes();
+                let (mint_pda, mint_bump) = Pubkey::find_program_address(
+                    &[MINT.as_bytes(), creator_key.as_ref(), &nonce_bytes],
+                    &crate::ID,
+                );
+                require_keys_eq!(token.key(), mint_pda, PumpfunError::IncorrectMintAccount);
+
+                create_account_with_signer(
+                    token.to_account_info(),
+                    mint_space,
+                    &token::ID,
+                    creator,
+                    &self.system_program,
+                    &[&[MINT.as_bytes(), creator_key.as_ref(), &nonce_bytes, &[mint_bump]]],
+                )?;
+            }
+            None => {
+                require!(token.is_signer, PumpfunError::MintSignatureRequired);
+
+                create_account_with_signer(
+                    token.to_account_info(),
+                    mint_space,
+                    &token::ID,
+                    creator,
+                    &self.system_program,
+                    &[],
+                )?;
+            }
+        }
+        token::initialize_mint2(
+            CpiContext::new(
+                self.token_program.to_account_info(),
+                token::InitializeMint2 {
+                    mint: token.to_account_info(),
+                },
+            ),
+            decimals,
+            &global_vault.key(),
+            None,
+        )?;
 
         // create token launch pda
         bonding_curve.token_mint = token.key();
@@ -173,27 +306,58 @@ impl<'info> Launch<'info> {
         bonding_curve.init_lamport = reserve_lamport;
         bonding_curve.reserve_lamport = reserve_lamport;
         bonding_curve.reserve_token = init_bonding_curve;
-        bonding_curve.curve_limit = global_config.curve_limit;
+        bonding_curve.curve_limit = curve_limit;
+        bonding_curve.has_curve_vault = true;
+        bonding_curve.curve_vault_bump = curve_vault_bump;
+        bonding_curve.last_price_update_ts = Clock::get()?.unix_timestamp;
+        bonding_curve.config_tier = global_config.tier_id;
+        bonding_curve.has_candles = true;
+
+        // set up the allowlisted presale phase
+        if let Some(presale) = presale {
+            let current_timestamp = Clock::get()?.unix_timestamp;
+            require!(
+                presale.merkle_root != [0u8; 32]
+                    && presale.end_ts > current_timestamp
+                    && presale.end_ts - current_timestamp <= MAX_PRESALE_DURATION
+                    && presale.wallet_cap > 0,
+                PumpfunError::InvalidPresaleConfig
+            );
+
+            bonding_curve.presale_merkle_root = presale.merkle_root;
+            bonding_curve.presale_end_ts = presale.end_ts;
+            bonding_curve.presale_wallet_cap = presale.wallet_cap;
+        }
+
+        // fund curve vault with rent exemption
+        if curve_vault.lamports() == 0 {
+            sol_transfer_from_user(
+                creator,
+                curve_vault.clone(),
+                &self.system_program,
+                self.rent.minimum_balance(0),
+            )?;
+        }
 
-        // create global token account
+        // create curve vault token account
         associated_token::create(CpiContext::new(
             self.associated_token_program.to_account_info(),
             associated_token::Create {
                 payer: creator.to_account_info(),
-                associated_token: global_token_account.to_account_info(),
-                authority: global_vault.to_account_info(),
+                associated_token: curve_vault_ata.to_account_info(),
+                authority: curve_vault.to_account_info(),
                 mint: token.to_account_info(),
                 token_program: self.token_program.to_account_info(),
                 system_program: self.system_program.to_account_info(),
             },
         ))?;
-        // create team token account
+        // create vesting escrow token account
         anchor_spl::associated_token::create(CpiContext::new(
             self.associated_token_program.to_account_info(),
             anchor_spl::associated_token::Create {
                 payer: creator.to_account_info(),
-                associated_token: team_wallet_ata.to_account_info(),
-                authority: team_wallet.to_account_info(),
+                associated_token: vesting_escrow_ata.to_account_info(),
+                authority: self.vesting_escrow.to_account_info(),
 
                 mint: token.to_account_info(),
                 system_program: self.system_program.to_account_info(),
@@ -202,13 +366,13 @@ impl<'info> Launch<'info> {
         ))?;
         let signer_seeds: &[&[&[u8]]] = &[&[GLOBAL.as_bytes(), &[global_vault_bump]]];
 
-        // mint tokens to bonding curve & team
+        // mint tokens to bonding curve & the team's vesting escrow
         token::mint_to(
             CpiContext::new_with_signer(
                 self.token_program.to_account_info(),
                 token::MintTo {
                     mint: token.to_account_info(),
-                    to: global_token_account.to_account_info(),
+                    to: curve_vault_ata.to_account_info(),
                     authority: global_vault.to_account_info(),
                 },
                 signer_seeds,
@@ -220,7 +384,7 @@ impl<'info> Launch<'info> {
                 self.token_program.to_account_info(),
                 token::MintTo {
                     mint: token.to_account_info(),
-                    to: team_wallet_ata.to_account_info(),
+                    to: vesting_escrow_ata.to_account_info(),
                     authority: global_vault.to_account_info(),
                 },
                 signer_seeds,
@@ -228,6 +392,49 @@ impl<'info> Launch<'info> {
             amount_to_team,
         )?;
 
+        //  remaining accounts are passed in pairs per allocation: [recipient, recipient_ata]
+        for ((allocation, amount), accounts) in allocations
+            .iter()
+            .zip(allocation_amounts)
+            .zip(remaining_accounts.chunks(2))
+        {
+            let (recipient, recipient_ata) = (&accounts[0], &accounts[1]);
+            require_keys_eq!(
+                recipient.key(),
+                allocation.recipient,
+                PumpfunError::InvalidRemainingAccounts
+            );
+            require_keys_eq!(
+                recipient_ata.key(),
+                associated_token::get_associated_token_address(&allocation.recipient, &token.key()),
+                PumpfunError::InvalidRemainingAccounts
+            );
+
+            associated_token::create(CpiContext::new(
+                self.associated_token_program.to_account_info(),
+                associated_token::Create {
+                    payer: creator.to_account_info(),
+                    associated_token: recipient_ata.clone(),
+                    authority: recipient.clone(),
+                    mint: token.to_account_info(),
+                    token_program: self.token_program.to_account_info(),
+                    system_program: self.system_program.to_account_info(),
+                },
+            ))?;
+            token::mint_to(
+                CpiContext::new_with_signer(
+                    self.token_program.to_account_info(),
+                    token::MintTo {
+                        mint: token.to_account_info(),
+                        to: recipient_ata.clone(),
+                        authority: global_vault.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+                amount,
+            )?;
+        }
+
         // create metadata
         metadata::create_metadata_accounts_v3(
             CpiContext::new_with_signer(
@@ -273,12 +480,56 @@ impl<'info> Launch<'info> {
 
         bonding_curve.is_completed = false;
 
-        msg!(
-            "NewToken: {} {}",
-            bonding_curve.token_mint,
-            bonding_curve.creator
-        );
+        // set up price history
+        let mut curve_candles = self.curve_candles.load_init()?;
+        curve_candles.bonding_curve = bonding_curve.key();
+        curve_candles.interval = CANDLE_INTERVAL;
+
+        // team allocation unlocks per the config's schedule
+        let vesting_escrow = &mut self.vesting_escrow;
+        vesting_escrow.bonding_curve = bonding_curve.key();
+        vesting_escrow.mint = bonding_curve.token_mint;
+        vesting_escrow.total_amount = amount_to_team;
+        vesting_escrow.start_ts = bonding_curve.last_price_update_ts;
+        vesting_escrow.schedule = global_config.team_vesting;
+        vesting_escrow.bump = vesting_escrow_bump;
+
+        // launch fee, reduced for allowlisted creators
+        let launch_fee = global_config.launch_fee_for(&creator.key());
+        if launch_fee > 0 {
+            sol_transfer_from_user(
+                creator,
+                team_wallet.to_account_info(),
+                &self.system_program,
+                launch_fee,
+            )?;
+        }
 
-        Ok(())
+        // record the launch in the creator's history
+        let creator_registry = &mut self.creator_registry;
+        creator_registry.creator = bonding_curve.creator;
+        creator_registry.record_launch(
+            &global_config.launch_rate_limit,
+            bonding_curve.last_price_update_ts,
+        )?;
+        creator_registry.last_mint = bonding_curve.token_mint;
+
+        Ok(LaunchEvent {
+            creator: bonding_curve.creator,
+            mint: bonding_curve.token_mint,
+            bonding_curve: bonding_curve.key(),
+            decimals,
+            token_supply,
+            curve_token_amount: init_bonding_curve,
+            team_token_amount: amount_to_team,
+            virtual_lamport_reserves: reserve_lamport,
+            curve_limit: bonding_curve.curve_limit,
+            presale_end_ts: bonding_curve.presale_end_ts,
+            config_tier: bonding_curve.config_tier,
+            launch_fee,
+            allocations,
+            allocated_token_amount,
+            timestamp: Clock::get()?.unix_timestamp,
+        })
     }
 }
@@ -0,0 +1,61 @@
+use crate::{
+    constants::CONFIG,
+    errors::*,
+    events::ConfigMigratedEvent,
+    state::{Config, LegacyConfig},
+    utils::realloc_with_rent,
+};
+use anchor_lang::{prelude::*, system_program, Discriminator};
+use borsh::BorshDeserialize;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct MigrateConfig<'info> {
+    //  anyone can migrate the config, payer covers the extra rent
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    /// CHECK: deserialized in the instruction, the deployed config is smaller than the current layout
+    #[account(
+        mut,
+        seeds = [CONFIG.as_bytes()],
+        bump,
+        owner = crate::ID,
+    )]
+    config: AccountInfo<'info>,
+
+    #[account(address = system_program::ID)]
+    system_program: Program<'info, System>,
+}
+
+impl MigrateConfig<'_> {
+    pub fn process(&mut self) -> Result<ConfigMigratedEvent> {
+        let config = {
+            let data = self.config.try_borrow_data()?;
+            if data.len() < 8 || data[0..8] != Config::DISCRIMINATOR {
+                return err!(PumpfunError::IncorrectConfigAccount);
+            }
+            if Config::try_deserialize(&mut &data[..]).is_ok() {
+                return err!(PumpfunError::ConfigAlreadyMigrated);
+            }
+
+            LegacyConfig::deserialize(&mut &data[8..])?.migrate()
+        };
+
+        let serialized_config = [&Config::DISCRIMINATOR, config.try_to_vec()?.as_slice()].concat();
+        realloc_with_rent(
+            self.config.clone(),
+            serialized_config.len(),
+            &self.payer,
+            &self.system_program,
+        )?;
+        (self.config.try_borrow_mut_data()?[..serialized_config.len()])
+            .copy_from_slice(serialized_config.as_slice());
+
+        Ok(ConfigMigratedEvent {
+            payer: self.payer.key(),
+            config,
+            timestamp: Clock::get()?.unix_timestamp,
+        })
+    }
+}
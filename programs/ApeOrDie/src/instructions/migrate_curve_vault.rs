@@ -0,0 +1,178 @@
+use crate::{
+    constants::{BONDING_CURVE, CURVE_VAULT, GLOBAL},
+    errors::*,
+    events::CurveVaultMigratedEvent,
+    state::BondingCurve,
+    utils::{sol_transfer_from_user, sol_transfer_with_signer, token_transfer_with_signer},
+};
+use anchor_lang::{prelude::*, system_program};
+use anchor_spl::{
+    associated_token::{self, AssociatedToken},
+    token::{self, Mint, Token, TokenAccount},
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct MigrateCurveVault<'info> {
+    //  anyone can migrate a curve, payer covers the extra rent
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    /// CHECK: global vault pda which stores SOL of legacy curves
+    #[account(
+        mut,
+        seeds = [GLOBAL.as_bytes()],
+        bump,
+    )]
+    pub global_vault: AccountInfo<'info>,
+
+    token_mint: Box<Account<'info, Mint>>,
+
+    /// CHECK: deserialized in the instruction, legacy accounts are smaller than the current layout
+    #[account(
+        mut,
+        seeds = [BONDING_CURVE.as_bytes(), &token_mint.key().to_bytes()],
+        bump
+    )]
+    bonding_curve: AccountInfo<'info>,
+
+    /// CHECK: curve vault pda which stores SOL of this curve
+    #[account(
+        mut,
+        seeds = [CURVE_VAULT.as_bytes(), bonding_curve.key().as_ref()],
+        bump,
+    )]
+    pub curve_vault: AccountInfo<'info>,
+
+    /// CHECK: ata of global vault
+    #[account(
+        mut,
+        seeds = [
+            global_vault.key().as_ref(),
+            anchor_spl::token::spl_token::ID.as_ref(),
+            token_mint.key().as_ref(),
+        ],
+        bump,
+        seeds::program = anchor_spl::associated_token::ID
+    )]
+    global_vault_ata: AccountInfo<'info>,
+
+    /// CHECK: ata of curve vault, created in instruction
+    #[account(
+        mut,
+        seeds = [
+            curve_vault.key().as_ref(),
+            anchor_spl::token::spl_token::ID.as_ref(),
+            token_mint.key().as_ref(),
+        ],
+        bump,
+        seeds::program = anchor_spl::associated_token::ID
+    )]
+    curve_vault_ata: AccountInfo<'info>,
+
+    #[account(address = system_program::ID)]
+    system_program: Program<'info, System>,
+
+    #[account(address = token::ID)]
+    token_program: Program<'info, Token>,
+
+    #[account(address = associated_token::ID)]
+    associated_token_program: Program<'info, AssociatedToken>,
+}
+
+impl<'info> MigrateCurveVault<'info> {
+    pub fn process(&mut self, global_vault_bump: u8, curve_vault_bump: u8) -> Result<CurveVaultMigratedEvent> {
+        //  grow legacy bonding curve accounts to the current layout, new fields are zeroed
+        let bonding_curve_len = 8 + BondingCurve::INIT_SPACE;
+        if self.bonding_curve.data_len() < bonding_curve_len {
+            let rent_cost = Rent::get()?.minimum_balance(bonding_curve_len);
+            let lamport_delta = rent_cost.saturating_sub(self.bonding_curve.lamports());
+            if lamport_delta > 0 {
+                sol_transfer_from_user(
+                    &self.payer,
+                    self.bonding_curve.clone(),
+                    &self.system_program,
+                    lamport_delta,
+                )?;
+            }
+            self.bonding_curve.realloc(bonding_curve_len, true)?;
+        }
+
+        let mut bonding_curve =
+            BondingCurve::try_deserialize(&mut &**self.bonding_curve.try_borrow_data()?)?;
+        require!(
+            !bonding_curve.has_curve_vault,
+            PumpfunError::CurveVaultAlreadyMigrated
+        );
+
+        // fund curve vault with rent exemption
+        if self.curve_vault.lamports() == 0 {
+            sol_transfer_from_user(
+                &self.payer,
+                self.curve_vault.clone(),
+                &self.system_program,
+                Rent::get()?.minimum_balance(0),
+            )?;
+        }
+
+        //  create curve vault ata, if it doesn't exist
+        if self.curve_vault_ata.data_is_empty() {
+            anchor_spl::associated_token::create(CpiContext::new(
+                self.associated_token_program.to_account_info(),
+                anchor_spl::associated_token::Create {
+                    payer: self.payer.to_account_info(),
+                    associated_token: self.curve_vault_ata.to_account_info(),
+                    authority: self.curve_vault.to_account_info(),
+
+                    mint: self.token_mint.to_account_info(),
+                    system_program: self.system_program.to_account_info(),
+                    token_program: self.token_program.to_account_info(),
+                },
+            ))?;
+        }
+
+        let signer_seeds: &[&[&[u8]]] = &[&[GLOBAL.as_bytes(), &[global_vault_bump]]];
+
+        //  real SOL of the curve, the virtual reserve never left the user
+        let lamport_amount = bonding_curve
+            .reserve_lamport
+            .saturating_sub(bonding_curve.init_lamport);
+        let token_amount =
+            TokenAccount::try_deserialize(&mut &**self.global_vault_ata.try_borrow_data()?)?
+                .amount;
+
+        if lamport_amount > 0 {
+            sol_transfer_with_signer(
+                self.global_vault.clone(),
+                self.curve_vault.clone(),
+                &self.system_program,
+                signer_seeds,
+                lamport_amount,
+            )?;
+        }
+
+        if token_amount > 0 {
+            token_transfer_with_signer(
+                self.global_vault_ata.clone(),
+                self.global_vault.clone(),
+                self.curve_vault_ata.clone(),
+                &self.token_program,
+                signer_seeds,
+                token_amount,
+            )?;
+        }
+
+        bonding_curve.has_curve_vault = true;
+        bonding_curve.curve_vault_bump = curve_vault_bump;
+        bonding_curve.try_serialize(&mut &mut self.bonding_curve.try_borrow_mut_data()?[..])?;
+
+        Ok(CurveVaultMigratedEvent {
+            mint: self.token_mint.key(),
+            bonding_curve: self.bonding_curve.key(),
+            curve_vault: self.curve_vault.key(),
+            lamport_amount,
+            token_amount,
+            timestamp: Clock::get()?.unix_timestamp,
+        })
+    }
+}
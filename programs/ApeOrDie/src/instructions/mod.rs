@@ -1,12 +1,48 @@
 pub mod configure;
 pub use configure::*;
+pub mod update_config;
+pub use update_config::*;
+pub mod queue_config_change;
+pub use queue_config_change::*;
+pub mod execute_config_change;
+pub use execute_config_change::*;
+pub mod cancel_config_change;
+pub use cancel_config_change::*;
 pub mod nominate_authority;
 pub use nominate_authority::*;
 pub mod accept_authority;
 pub use accept_authority::*;
+pub mod cancel_nomination;
+pub use cancel_nomination::*;
+pub mod renounce_authority;
+pub use renounce_authority::*;
+pub mod nominate_role;
+pub use nominate_role::*;
+pub mod accept_role;
+pub use accept_role::*;
+pub mod revoke_role;
+pub use revoke_role::*;
+pub mod set_paused;
+pub use set_paused::*;
 pub mod launch;
 pub use launch::*;
+pub mod update_metadata;
+pub use update_metadata::*;
 pub mod swap;
 pub use swap::*;
 pub mod withdraw;
 pub use withdraw::*;
+pub mod claim_vested;
+pub use claim_vested::*;
+pub mod sweep_fees;
+pub use sweep_fees::*;
+pub mod migrate_config;
+pub use migrate_config::*;
+pub mod migrate_curve_vault;
+pub use migrate_curve_vault::*;
+pub mod audit;
+pub use audit::*;
+pub mod init_candles;
+pub use init_candles::*;
+pub mod init_creator_registry;
+pub use init_creator_registry::*;
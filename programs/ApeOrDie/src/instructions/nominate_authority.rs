@@ -1,8 +1,10 @@
 use constants::CONFIG;
 use errors::PumpfunError;
+use events::AuthorityNominatedEvent;
 
 use crate::*;
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct NominateAuthority<'info> {
     // Current admin
@@ -15,15 +17,23 @@ pub struct NominateAuthority<'info> {
     //  Stores admin address
     #[account(
         mut,
-        seeds = [CONFIG.as_bytes()],
+        seeds = [CONFIG.as_bytes(), Config::tier_seed(&global_config.tier_id)],
         bump,
     )]
     global_config: Box<Account<'info, Config>>,
 }
 
 impl NominateAuthority<'_> {
-    pub fn process(&mut self, new_admin: Pubkey) -> Result<()> {
+    pub fn process(&mut self, new_admin: Pubkey) -> Result<AuthorityNominatedEvent> {
+        let timestamp = Clock::get()?.unix_timestamp;
         self.global_config.pending_authority = new_admin;
-        Ok(())
+        self.global_config.pending_authority_nominated_at = timestamp;
+
+        Ok(AuthorityNominatedEvent {
+            authority: self.admin.key(),
+            pending_authority: new_admin,
+            expires_at: self.global_config.nomination_expiry(timestamp),
+            timestamp,
+        })
     }
 }
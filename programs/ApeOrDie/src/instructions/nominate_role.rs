@@ -0,0 +1,42 @@
+use constants::CONFIG;
+use errors::PumpfunError;
+use events::RoleNominatedEvent;
+use state::Role;
+
+use crate::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct NominateRole<'info> {
+    //  Only the admin hands out roles
+    #[account(
+        mut,
+        constraint = global_config.authority == *admin.key @PumpfunError::IncorrectAuthority
+    )]
+    pub admin: Signer<'info>,
+
+    //  Stores role holders
+    #[account(
+        mut,
+        seeds = [CONFIG.as_bytes(), Config::tier_seed(&global_config.tier_id)],
+        bump,
+    )]
+    global_config: Box<Account<'info, Config>>,
+}
+
+impl NominateRole<'_> {
+    pub fn process(&mut self, role: Role, new_holder: Pubkey) -> Result<RoleNominatedEvent> {
+        let timestamp = Clock::get()?.unix_timestamp;
+        let assignment = self.global_config.role_mut(role);
+        assignment.pending = new_holder;
+        assignment.nominated_at = timestamp;
+
+        Ok(RoleNominatedEvent {
+            role,
+            authority: self.admin.key(),
+            pending_holder: new_holder,
+            expires_at: self.global_config.nomination_expiry(timestamp),
+            timestamp,
+        })
+    }
+}
@@ -0,0 +1,75 @@
+use crate::{
+    constants::{CONFIG, PENDING_CONFIG_CHANGE},
+    errors::*,
+    events::ConfigChangeQueuedEvent,
+    state::{Config, ConfigChange, PendingConfigChange},
+};
+use anchor_lang::{prelude::*, system_program};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct QueueConfigChange<'info> {
+    //  authority, or the fee admin for fee changes
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG.as_bytes(), Config::tier_seed(&global_config.tier_id)],
+        bump,
+    )]
+    global_config: Box<Account<'info, Config>>,
+
+    //  only one change can be queued at a time
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + PendingConfigChange::INIT_SPACE,
+        seeds = [PENDING_CONFIG_CHANGE.as_bytes(), global_config.key().as_ref()],
+        bump
+    )]
+    pending_config_change: Box<Account<'info, PendingConfigChange>>,
+
+    #[account(address = system_program::ID)]
+    system_program: Program<'info, System>,
+}
+
+impl QueueConfigChange<'_> {
+    pub fn process(&mut self, change: ConfigChange) -> Result<ConfigChangeQueuedEvent> {
+        require!(
+            self.global_config.can_change(&change, self.admin.key),
+            PumpfunError::MissingRole
+        );
+
+        //  the config can't grow on execute, new discounts go through `set_launch_fee_discounts`
+        if let ConfigChange::LaunchFeeDiscount(discount) = &change {
+            require!(
+                self.global_config
+                    .launch_fee_discounts
+                    .iter()
+                    .any(|existing| existing.creator == discount.creator),
+                PumpfunError::InvalidLaunchFeeDiscounts
+            );
+        }
+
+        //  reject changes that could never be executed
+        let mut config = (**self.global_config).clone();
+        change.apply(&mut config);
+        config.validate()?;
+
+        let timestamp = Clock::get()?.unix_timestamp;
+        let eta = timestamp
+            .checked_add(self.global_config.config_change_delay)
+            .ok_or(PumpfunError::OverflowOrUnderflowOccurred)?;
+
+        self.pending_config_change.config = self.global_config.key();
+        self.pending_config_change.change = change.clone();
+        self.pending_config_change.eta = eta;
+
+        Ok(ConfigChangeQueuedEvent {
+            authority: self.admin.key(),
+            change,
+            eta,
+            timestamp,
+        })
+    }
+}
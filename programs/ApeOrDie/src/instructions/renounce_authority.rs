@@ -0,0 +1,59 @@
+use constants::CONFIG;
+use errors::PumpfunError;
+use events::AuthorityRenouncedEvent;
+use state::RoleAssignment;
+
+use crate::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RenounceAuthority<'info> {
+    // Current admin
+    #[account(
+        mut,
+        constraint = global_config.authority == *admin.key @PumpfunError::IncorrectAuthority
+    )]
+    pub admin: Signer<'info>,
+
+    //  Stores admin address
+    #[account(
+        mut,
+        seeds = [CONFIG.as_bytes(), Config::tier_seed(&global_config.tier_id)],
+        bump,
+    )]
+    global_config: Box<Account<'info, Config>>,
+}
+
+impl RenounceAuthority<'_> {
+    pub fn process(&mut self) -> Result<AuthorityRenouncedEvent> {
+        //  the config is frozen from here on, it has to be a working one
+        self.global_config.validate()?;
+
+        //  nobody could unpause afterwards
+        require!(!self.global_config.paused, PumpfunError::ProgramPaused);
+
+        //  completed curves can only be withdrawn by the migration operator once the authority is gone
+        require!(
+            self.global_config.migration_operator.holder != Pubkey::default(),
+            PumpfunError::MigrationOperatorRequired
+        );
+
+        let config = &mut self.global_config;
+        config.authority = Pubkey::default();
+        config.pending_authority = Pubkey::default();
+        config.pending_authority_nominated_at = 0;
+
+        //  only the migration operator survives, it can't be revoked anymore but only moves
+        //  completed curves, the pause guardian could freeze trading for good so it goes too
+        config.fee_admin = RoleAssignment::default();
+        config.launch_moderator = RoleAssignment::default();
+        config.pause_guardian = RoleAssignment::default();
+        config.migration_operator.pending = Pubkey::default();
+        config.migration_operator.nominated_at = 0;
+
+        Ok(AuthorityRenouncedEvent {
+            previous_authority: self.admin.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        })
+    }
+}
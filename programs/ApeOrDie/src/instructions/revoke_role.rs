@@ -0,0 +1,39 @@
+use constants::CONFIG;
+use errors::PumpfunError;
+use events::RoleRevokedEvent;
+use state::Role;
+
+use crate::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RevokeRole<'info> {
+    //  Only the admin takes roles back
+    #[account(
+        mut,
+        constraint = global_config.authority == *admin.key @PumpfunError::IncorrectAuthority
+    )]
+    pub admin: Signer<'info>,
+
+    //  Stores role holders
+    #[account(
+        mut,
+        seeds = [CONFIG.as_bytes(), Config::tier_seed(&global_config.tier_id)],
+        bump,
+    )]
+    global_config: Box<Account<'info, Config>>,
+}
+
+impl RevokeRole<'_> {
+    pub fn process(&mut self, role: Role) -> Result<RoleRevokedEvent> {
+        //  drops the pending nomination too
+        let previous = std::mem::take(self.global_config.role_mut(role));
+
+        Ok(RoleRevokedEvent {
+            role,
+            authority: self.admin.key(),
+            previous_holder: previous.holder,
+            timestamp: Clock::get()?.unix_timestamp,
+        })
+    }
+}
@@ -0,0 +1,34 @@
+use constants::CONFIG;
+use errors::PumpfunError;
+use events::PausedEvent;
+use state::Role;
+
+use crate::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetPaused<'info> {
+    #[account(
+        constraint = global_config.has_role(Role::PauseGuardian, guardian.key) @PumpfunError::MissingRole
+    )]
+    pub guardian: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [CONFIG.as_bytes(), Config::tier_seed(&global_config.tier_id)],
+        bump,
+    )]
+    global_config: Box<Account<'info, Config>>,
+}
+
+impl SetPaused<'_> {
+    pub fn process(&mut self, paused: bool) -> Result<PausedEvent> {
+        self.global_config.paused = paused;
+
+        Ok(PausedEvent {
+            guardian: self.guardian.key(),
+            paused,
+            timestamp: Clock::get()?.unix_timestamp,
+        })
+    }
+}
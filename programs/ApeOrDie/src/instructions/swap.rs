@@ -4,89 +4,70 @@ use anchor_spl::{
     token::{self, Mint, Token},
 };
 use crate::{
-    constants::{BONDING_CURVE, CONFIG, GLOBAL}, 
+    constants::{BONDING_CURVE, CANDLES, CONFIG, CREATOR_REGISTRY, CURVE_VAULT, PRESALE_RECEIPT}, 
     errors::PumpfunError, 
-    state::{BondingCurve, Config, BondingCurveAccount}
+    events::{CompleteEvent, SwapEvent},
+    state::{BondingCurve, Config, BondingCurveAccount, CreatorRegistry, CurveCandles, PresaleReceipt},
+    utils::{presale_leaf, verify_merkle_proof}
 };
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct Swap<'info> {
     #[account(
-        seeds = [CONFIG.as_bytes()],
+        seeds = [CONFIG.as_bytes(), Config::tier_seed(&global_config.tier_id)],
         bump,
+        constraint = !global_config.paused @PumpfunError::ProgramPaused
     )]
     global_config: Box<Account<'info, Config>>,
     
-    //  team wallet
-    /// CHECK: should be same with the address in the global_config
     #[account(
         mut,
-        constraint = global_config.team_wallet == team_wallet.key() @PumpfunError::IncorrectAuthority
-    )]
-    pub team_wallet: AccountInfo<'info>,
-
-    /// CHECK: ata of team wallet
-    #[account(
-        mut,
-        seeds = [
-            team_wallet.key().as_ref(),
-            anchor_spl::token::spl_token::ID.as_ref(),
-            token_mint.key().as_ref(),
-        ],
+        seeds = [BONDING_CURVE.as_bytes(), &token_mint.key().to_bytes()], 
         bump,
-        seeds::program = anchor_spl::associated_token::ID
-    )]
-    team_wallet_ata: AccountInfo<'info>,
-
-    /// CHECK: dev wallet for fee
-    #[account(
-        mut,
-        constraint = global_config.dev_wallet == dev_wallet.key() @PumpfunError::IncorrectAuthority
+        constraint = bonding_curve.config_tier == global_config.tier_id @PumpfunError::ConfigTierMismatch
     )]
-    pub dev_wallet: Option<AccountInfo<'info>>,
+    bonding_curve: Account<'info, BondingCurve>,
 
-    /// CHECK: ata of dev wallet
+    //  required once the curve has price history, legacy curves trade without it
     #[account(
         mut,
-        seeds = [
-            dev_wallet.as_ref().map(|w| w.key()).unwrap_or_default().as_ref(),
-            anchor_spl::token::spl_token::ID.as_ref(),
-            token_mint.key().as_ref(),
-        ],
-        bump,
-        seeds::program = anchor_spl::associated_token::ID
+        seeds = [CANDLES.as_bytes(), bonding_curve.key().as_ref()],
+        bump
     )]
-    dev_wallet_ata: Option<AccountInfo<'info>>,
+    curve_candles: Option<AccountLoader<'info, CurveCandles>>,
 
+    /// CHECK: creator stats pda, only updated once it is initialized
+    //  creators who launched before registries trade without one until `init_creator_registry`
     #[account(
         mut,
-        seeds = [BONDING_CURVE.as_bytes(), &token_mint.key().to_bytes()], 
+        seeds = [CREATOR_REGISTRY.as_bytes(), bonding_curve.creator.as_ref()],
         bump
     )]
-    bonding_curve: Account<'info, BondingCurve>,
+    creator_registry: AccountInfo<'info>,
 
-    /// CHECK: global vault pda which stores SOL
+    /// CHECK: curve vault pda which stores SOL of this curve
     #[account(
         mut,
-        seeds = [GLOBAL.as_bytes()],
-        bump,
+        seeds = [CURVE_VAULT.as_bytes(), bonding_curve.key().as_ref()],
+        bump = bonding_curve.curve_vault_bump,
     )]
-    pub global_vault: AccountInfo<'info>,
+    pub curve_vault: AccountInfo<'info>,
 
     pub token_mint: Box<Account<'info, Mint>>,
 
-    /// CHECK: ata of global vault
+    /// CHECK: ata of curve vault
     #[account(
         mut,
         seeds = [
-            global_vault.key().as_ref(),
+            curve_vault.key().as_ref(),
             anchor_spl::token::spl_token::ID.as_ref(),
             token_mint.key().as_ref(),
         ],
         bump,
         seeds::program = anchor_spl::associated_token::ID
     )]
-    global_ata: AccountInfo<'info>,
+    curve_vault_ata: AccountInfo<'info>,
 
     /// CHECK: ata of user
     #[account(
@@ -104,6 +85,16 @@ pub struct Swap<'info> {
     #[account(mut)]
     pub user: Signer<'info>,
 
+    //  only required for buys during the presale phase
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + PresaleReceipt::INIT_SPACE,
+        seeds = [PRESALE_RECEIPT.as_bytes(), bonding_curve.key().as_ref(), user.key().as_ref()],
+        bump
+    )]
+    presale_receipt: Option<Box<Account<'info, PresaleReceipt>>>,
+
     #[account(address = system_program::ID)]
     pub system_program: Program<'info, System>,
 
@@ -115,7 +106,8 @@ pub struct Swap<'info> {
 }
 
 impl<'info> Swap<'info> { 
-pub fn process(&mut self, amount: u64, direction: u8, minimum_receive_amount: u64, deadline: i64, global_vault_bump:u8) -> Result<u64> {
+#[allow(clippy::too_many_arguments)]
+pub fn process(&mut self, amount: u64, direction: u8, minimum_receive_amount: u64, deadline: i64, presale_proof: Vec<[u8; 32]>) -> Result<(SwapEvent, Option<CompleteEvent>)> {
     // Check deadline hasn't passed
     let current_timestamp = Clock::get()?.unix_timestamp;
     require!(
@@ -131,17 +123,51 @@ pub fn process(&mut self, amount: u64, direction: u8, minimum_receive_amount: u6
         PumpfunError::CurveAlreadyCompleted
     );
 
-    let source = &mut self.global_vault.to_account_info();
+    //  legacy curves have to move their funds out of the global vault first
+    require!(
+        bonding_curve.has_curve_vault,
+        PumpfunError::CurveVaultNotMigrated
+    );
+
+    //  a trader can't leave a price move out of the candles
+    require!(
+        !bonding_curve.has_candles || self.curve_candles.is_some(),
+        PumpfunError::CurveCandlesRequired
+    );
+
+    //  during the presale only allowlisted wallets can buy, up to their cap
+    if direction == 0 && bonding_curve.is_presale_active(current_timestamp) {
+        let user_key = self.user.key();
+        require!(
+            verify_merkle_proof(&presale_proof, &bonding_curve.presale_merkle_root, presale_leaf(&user_key)),
+            PumpfunError::NotOnPresaleAllowlist
+        );
+
+        let receipt = self
+            .presale_receipt
+            .as_mut()
+            .ok_or(PumpfunError::PresaleReceiptRequired)?;
+        receipt.bonding_curve = bonding_curve.key();
+        receipt.buyer = user_key;
+
+        // the buy is capped by the remaining curve capacity, same as in the swap
+        let spent = amount.min(bonding_curve.curve_limit.saturating_sub(bonding_curve.reserve_lamport));
+        let purchased = receipt
+            .purchased_lamports
+            .checked_add(spent)
+            .ok_or(PumpfunError::OverflowOrUnderflowOccurred)?;
+        require!(
+            purchased <= bonding_curve.presale_wallet_cap,
+            PumpfunError::PresaleCapExceeded
+        );
+        receipt.purchased_lamports = purchased;
+    }
+
+    let source = &mut self.curve_vault.to_account_info();
 
     let token = &mut self.token_mint;
-    let team_wallet = &mut self.team_wallet;
-    let team_wallet_ata = &mut self.team_wallet_ata;
     let user_ata = &mut self.user_ata;
-    
-    // Get dev wallet accounts if enabled
-    let dev_wallet_ref = self.dev_wallet.as_ref();
-    let dev_wallet_ata_ref = self.dev_wallet_ata.as_ref();
-    
+
     //  create user wallet ata, if it doean't exit
     if user_ata.data_is_empty() {
         anchor_spl::associated_token::create(CpiContext::new(
@@ -158,57 +184,22 @@ pub fn process(&mut self, amount: u64, direction: u8, minimum_receive_amount: u6
         ))?;
     }
 
-    //  create team wallet ata, if it doesn't exist
-    if team_wallet_ata.data_is_empty() {
-        anchor_spl::associated_token::create(CpiContext::new(
-            self.associated_token_program.to_account_info(),
-            anchor_spl::associated_token::Create {
-                payer: self.user.to_account_info(),
-                associated_token: team_wallet_ata.to_account_info(),
-                authority: team_wallet.to_account_info(),
-
-                mint: token.to_account_info(),
-                system_program: self.system_program.to_account_info(),
-                token_program: self.token_program.to_account_info(),
-            }
-        ))?;
-    }
-    
-    // Create dev wallet ATA if needed and enabled
-    if self.global_config.dev_fee_enabled {
-        if let (Some(dev_wallet_info), Some(dev_wallet_ata_info)) = (dev_wallet_ref, dev_wallet_ata_ref) {
-            if dev_wallet_ata_info.data_is_empty() {
-                anchor_spl::associated_token::create(CpiContext::new(
-                    self.associated_token_program.to_account_info(),
-                    anchor_spl::associated_token::Create {
-                        payer: self.user.to_account_info(),
-                        associated_token: dev_wallet_ata_info.to_account_info(),
-                        authority: dev_wallet_info.to_account_info(),
-                        mint: token.to_account_info(),
-                        system_program: self.system_program.to_account_info(),
-                        token_program: self.token_program.to_account_info(),
-                    }
-                ))?;
-            }
-        }
-    }
-
+    let bonding_curve_key = bonding_curve.key();
     let signer_seeds: &[&[&[u8]]] = &[&[
-        GLOBAL.as_bytes(),
-        &[global_vault_bump],
+        CURVE_VAULT.as_bytes(),
+        bonding_curve_key.as_ref(),
+        &[bonding_curve.curve_vault_bump],
     ]];
 
     
-    let amount_out = bonding_curve.swap(
+    let price_before = bonding_curve.spot_price();
+
+    let swap_event = bonding_curve.swap(
         &self.global_config,
         token.as_ref(),
-        &mut self.global_ata,
+        &mut self.curve_vault_ata,
         user_ata,
         source,
-        team_wallet,
-        team_wallet_ata,
-        self.dev_wallet.as_mut(),
-        self.dev_wallet_ata.as_mut(),
         amount,
         direction,
         minimum_receive_amount,
@@ -218,8 +209,41 @@ pub fn process(&mut self, amount: u64, direction: u8, minimum_receive_amount: u6
         &self.token_program,
         &self.system_program,
     )?;
-    
-    Ok(amount_out)
+
+    //  volume is the SOL that entered or left the pool, fees included
+    let volume = if direction == 0 {
+        swap_event.amount_in
+    } else {
+        swap_event.amount_out + swap_event.platform_fee + swap_event.dev_fee
+    };
+    if let Some(curve_candles) = &self.curve_candles {
+        curve_candles.load_mut()?.record(
+            current_timestamp,
+            price_before,
+            bonding_curve.spot_price(),
+            volume,
+        );
+    }
+
+    //  volume can't realistically overflow, saturate rather than fail trades
+    if self.creator_registry.owner == &crate::ID {
+        let mut data = self.creator_registry.try_borrow_mut_data()?;
+        let mut creator_registry = CreatorRegistry::try_deserialize(&mut &data[..])?;
+        creator_registry.total_volume = creator_registry.total_volume.saturating_add(volume);
+        if bonding_curve.is_completed {
+            creator_registry.graduated = creator_registry.graduated.saturating_add(1);
+        }
+        creator_registry.try_serialize(&mut &mut data[..])?;
+    }
+
+    //  the curve was active before this swap, so it just completed
+    let complete_event = bonding_curve.is_completed.then_some(CompleteEvent {
+        user: swap_event.user,
+        mint: swap_event.mint,
+        bonding_curve: swap_event.bonding_curve,
+    });
+
+    Ok((swap_event, complete_event))
 }
 
 }
\ No newline at end of file
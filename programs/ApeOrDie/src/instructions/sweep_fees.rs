@@ -0,0 +1,108 @@
+use crate::{
+    constants::{BONDING_CURVE, CONFIG, CURVE_VAULT},
+    errors::*,
+    events::FeesSweptEvent,
+    state::{BondingCurve, Config},
+    utils::sol_transfer_with_signer,
+};
+use anchor_lang::{prelude::*, system_program};
+use anchor_spl::token::Mint;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SweepFees<'info> {
+    #[account(
+        seeds = [CONFIG.as_bytes(), Config::tier_seed(&global_config.tier_id)],
+        bump,
+    )]
+    global_config: Box<Account<'info, Config>>,
+
+    /// CHECK: should be same with the address in the global_config
+    #[account(
+        mut,
+        constraint = global_config.team_wallet == team_wallet.key() @PumpfunError::IncorrectTeamWallet
+    )]
+    pub team_wallet: AccountInfo<'info>,
+
+    //  dev fees stay accrued when the dev wallet isn't passed
+    /// CHECK: should be same with the address in the global_config
+    #[account(
+        mut,
+        constraint = global_config.dev_wallet == dev_wallet.key() @PumpfunError::IncorrectAuthority
+    )]
+    pub dev_wallet: Option<AccountInfo<'info>>,
+
+    token_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [BONDING_CURVE.as_bytes(), &token_mint.key().to_bytes()],
+        bump,
+        constraint = bonding_curve.config_tier == global_config.tier_id @PumpfunError::ConfigTierMismatch
+    )]
+    bonding_curve: Box<Account<'info, BondingCurve>>,
+
+    /// CHECK: curve vault pda which stores SOL of this curve
+    #[account(
+        mut,
+        seeds = [CURVE_VAULT.as_bytes(), bonding_curve.key().as_ref()],
+        bump = bonding_curve.curve_vault_bump,
+    )]
+    pub curve_vault: AccountInfo<'info>,
+
+    #[account(address = system_program::ID)]
+    system_program: Program<'info, System>,
+}
+
+impl SweepFees<'_> {
+    pub fn process(&mut self) -> Result<FeesSweptEvent> {
+        let bonding_curve = &mut self.bonding_curve;
+        require!(bonding_curve.has_curve_vault, PumpfunError::CurveVaultNotMigrated);
+
+        let platform_fee = bonding_curve.accrued_platform_fee;
+        let dev_fee = if self.dev_wallet.is_some() {
+            bonding_curve.accrued_dev_fee
+        } else {
+            0
+        };
+        require!(platform_fee > 0 || dev_fee > 0, PumpfunError::NoFeesToSweep);
+
+        let bonding_curve_key = bonding_curve.key();
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            CURVE_VAULT.as_bytes(),
+            bonding_curve_key.as_ref(),
+            &[bonding_curve.curve_vault_bump],
+        ]];
+
+        if platform_fee > 0 {
+            sol_transfer_with_signer(
+                self.curve_vault.clone(),
+                self.team_wallet.clone(),
+                &self.system_program,
+                signer_seeds,
+                platform_fee,
+            )?;
+            bonding_curve.accrued_platform_fee = 0;
+        }
+
+        if let Some(dev_wallet) = self.dev_wallet.as_ref().filter(|_| dev_fee > 0) {
+            sol_transfer_with_signer(
+                self.curve_vault.clone(),
+                dev_wallet.clone(),
+                &self.system_program,
+                signer_seeds,
+                dev_fee,
+            )?;
+            bonding_curve.accrued_dev_fee = 0;
+        }
+
+        Ok(FeesSweptEvent {
+            mint: self.token_mint.key(),
+            bonding_curve: bonding_curve_key,
+            team_wallet: self.team_wallet.key(),
+            platform_fee,
+            dev_fee,
+            timestamp: Clock::get()?.unix_timestamp,
+        })
+    }
+}
@@ -0,0 +1,134 @@
+use crate::{
+    constants::CONFIG,
+    errors::*,
+    events::{
+        CurveParamsUpdatedEvent, LaunchFeeDiscountsUpdatedEvent, LaunchLimitsUpdatedEvent,
+        MetadataPolicyUpdatedEvent,
+    },
+    state::{Config, CurveParams, LaunchFeeDiscount, LaunchLimits, MetadataPolicy, Role},
+    utils::realloc_with_rent,
+};
+use anchor_lang::{prelude::*, system_program};
+
+//  shared by the focused config setters
+//  fees and fee wallets are changed through the timelock instead
+//  the signer's role is checked per setter
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateConfig<'info> {
+    //  fee admin for curve params, launch moderator for launch limits
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [CONFIG.as_bytes(), Config::tier_seed(&global_config.tier_id)],
+        bump,
+    )]
+    global_config: Box<Account<'info, Config>>,
+
+    #[account(address = system_program::ID)]
+    system_program: Program<'info, System>,
+}
+
+impl UpdateConfig<'_> {
+    pub fn set_curve_params(&mut self, params: CurveParams) -> Result<CurveParamsUpdatedEvent> {
+        require!(
+            self.global_config.has_role(Role::FeeAdmin, self.admin.key),
+            PumpfunError::MissingRole
+        );
+
+        let old = self.global_config.curve_params();
+        self.global_config.set_curve_params(params.clone());
+        self.global_config.validate()?;
+
+        Ok(CurveParamsUpdatedEvent {
+            authority: self.admin.key(),
+            old,
+            new: params,
+            timestamp: Clock::get()?.unix_timestamp,
+        })
+    }
+
+    pub fn set_launch_limits(&mut self, params: LaunchLimits) -> Result<LaunchLimitsUpdatedEvent> {
+        require!(
+            self.global_config.has_role(Role::LaunchModerator, self.admin.key),
+            PumpfunError::MissingRole
+        );
+
+        let old = self.global_config.launch_limits();
+        self.global_config.set_launch_limits(params.clone());
+        self.global_config.validate()?;
+
+        self.realloc_config()?;
+
+        Ok(LaunchLimitsUpdatedEvent {
+            authority: self.admin.key(),
+            old,
+            new: params,
+            timestamp: Clock::get()?.unix_timestamp,
+        })
+    }
+
+    pub fn set_metadata_policy(
+        &mut self,
+        policy: MetadataPolicy,
+    ) -> Result<MetadataPolicyUpdatedEvent> {
+        require!(
+            self.global_config.has_role(Role::LaunchModerator, self.admin.key),
+            PumpfunError::MissingRole
+        );
+
+        let old = self.global_config.metadata_policy.clone();
+        self.global_config.metadata_policy = policy.clone();
+        self.global_config.validate()?;
+
+        self.realloc_config()?;
+
+        Ok(MetadataPolicyUpdatedEvent {
+            authority: self.admin.key(),
+            old,
+            new: policy,
+            timestamp: Clock::get()?.unix_timestamp,
+        })
+    }
+
+    //  adding or raising discounts lowers fees, so it skips the timelock
+    //  lowering or removing one raises a creator's fee and goes through `queue_config_change`
+    pub fn set_launch_fee_discounts(
+        &mut self,
+        discounts: Vec<LaunchFeeDiscount>,
+    ) -> Result<LaunchFeeDiscountsUpdatedEvent> {
+        require!(
+            self.global_config.has_role(Role::FeeAdmin, self.admin.key),
+            PumpfunError::MissingRole
+        );
+        require!(
+            self.global_config.keeps_launch_fee_discounts(&discounts),
+            PumpfunError::LaunchFeeDiscountReduced
+        );
+
+        let old = std::mem::replace(&mut self.global_config.launch_fee_discounts, discounts.clone());
+        self.global_config.validate()?;
+
+        self.realloc_config()?;
+
+        Ok(LaunchFeeDiscountsUpdatedEvent {
+            authority: self.admin.key(),
+            old,
+            new: discounts,
+            timestamp: Clock::get()?.unix_timestamp,
+        })
+    }
+
+    //  limits, policy & discounts are variable sized, grow the account before it is written back
+    fn realloc_config(&self) -> Result<()> {
+        let config_len = 8 + self.global_config.try_to_vec()?.len();
+        realloc_with_rent(
+            self.global_config.to_account_info(),
+            config_len,
+            &self.admin,
+            &self.system_program,
+        )
+    }
+}
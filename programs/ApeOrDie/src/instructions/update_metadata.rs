@@ -0,0 +1,110 @@
+use crate::{
+    constants::{BONDING_CURVE, CONFIG, GLOBAL, METADATA},
+    errors::*,
+    events::MetadataUpdatedEvent,
+    state::{BondingCurve, Config},
+};
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    metadata::{self, mpl_token_metadata::types::DataV2, Metadata},
+    token::Mint,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateMetadata<'info> {
+    #[account(
+        seeds = [CONFIG.as_bytes(), Config::tier_seed(&global_config.tier_id)],
+        bump,
+    )]
+    global_config: Box<Account<'info, Config>>,
+
+    /// CHECK: global vault pda, update authority of the metadata
+    #[account(
+        seeds = [GLOBAL.as_bytes()],
+        bump,
+    )]
+    pub global_vault: AccountInfo<'info>,
+
+    #[account(
+        constraint = bonding_curve.creator == creator.key() @PumpfunError::NotCurveCreator
+    )]
+    pub creator: Signer<'info>,
+
+    token_mint: Box<Account<'info, Mint>>,
+
+    //  metadata only changes while the curve is trading
+    #[account(
+        seeds = [BONDING_CURVE.as_bytes(), &token_mint.key().to_bytes()],
+        bump,
+        constraint = !bonding_curve.is_completed @PumpfunError::CurveAlreadyCompleted,
+        constraint = bonding_curve.config_tier == global_config.tier_id @PumpfunError::ConfigTierMismatch
+    )]
+    bonding_curve: Box<Account<'info, BondingCurve>>,
+
+    /// CHECK: passed to token metadata program
+    #[account(
+        mut,
+        seeds = [
+            METADATA.as_bytes(),
+            metadata::ID.as_ref(),
+            token_mint.key().as_ref(),
+        ],
+        bump,
+        seeds::program = metadata::ID
+    )]
+    token_metadata_account: UncheckedAccount<'info>,
+
+    #[account(address = metadata::ID)]
+    mpl_token_metadata_program: Program<'info, Metadata>,
+}
+
+impl UpdateMetadata<'_> {
+    pub fn process(
+        &mut self,
+        name: String,
+        symbol: String,
+        uri: String,
+        global_vault_bump: u8,
+    ) -> Result<MetadataUpdatedEvent> {
+        //  same rules as launch
+        self.global_config
+            .metadata_policy
+            .check(&name, &symbol, &uri)?;
+
+        let signer_seeds: &[&[&[u8]]] = &[&[GLOBAL.as_bytes(), &[global_vault_bump]]];
+
+        metadata::update_metadata_accounts_v2(
+            CpiContext::new_with_signer(
+                self.mpl_token_metadata_program.to_account_info(),
+                metadata::UpdateMetadataAccountsV2 {
+                    metadata: self.token_metadata_account.to_account_info(),
+                    update_authority: self.global_vault.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            None,
+            Some(DataV2 {
+                name: name.clone(),
+                symbol: symbol.clone(),
+                uri: uri.clone(),
+                seller_fee_basis_points: 0,
+                creators: None,
+                collection: None,
+                uses: None,
+            }),
+            None,
+            None,
+        )?;
+
+        Ok(MetadataUpdatedEvent {
+            creator: self.creator.key(),
+            mint: self.token_mint.key(),
+            bonding_curve: self.bonding_curve.key(),
+            name,
+            symbol,
+            uri,
+            timestamp: Clock::get()?.unix_timestamp,
+        })
+    }
+}
@@ -1,32 +1,26 @@
 use crate::{
-    constants::{BONDING_CURVE, CONFIG, GLOBAL}, errors::*, state::{BondingCurve, BondingCurveAccount, Config}, utils::{sol_transfer_with_signer, token_transfer_with_signer}
+    constants::{BONDING_CURVE, CONFIG, CURVE_VAULT, GLOBAL, METADATA}, errors::*, events::WithdrawEvent, state::{BondingCurve, BondingCurveAccount, Config, GraduationMetadata, Role}, utils::{sol_transfer_with_signer, token_transfer_with_signer}
 };
 use anchor_lang::{prelude::*, system_program};
 use anchor_spl::{
     associated_token::{self, AssociatedToken}, 
+    metadata::{self, Metadata},
     token::{self, Mint, Token, TokenAccount},
 };
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct Withdraw<'info> {
     #[account(
         mut,
-        seeds = [CONFIG.as_bytes()],
+        seeds = [CONFIG.as_bytes(), Config::tier_seed(&global_config.tier_id)],
         bump,
     )]
     global_config: Box<Account<'info, Config>>,
 
-    /// CHECK: global vault pda which stores SOL
     #[account(
         mut,
-        seeds = [GLOBAL.as_bytes()],
-        bump,
-    )]
-    pub global_vault: AccountInfo<'info>,
-    
-    #[account(
-        mut,
-        constraint = global_config.authority == admin.key() @PumpfunError::IncorrectAuthority
+        constraint = global_config.has_role(Role::MigrationOperator, admin.key) @PumpfunError::MissingRole
     )]
     admin: Signer<'info>,
 
@@ -35,22 +29,31 @@ pub struct Withdraw<'info> {
     #[account(
         mut,
         seeds = [BONDING_CURVE.as_bytes(), &token_mint.key().to_bytes()], 
-        bump
+        bump,
+        constraint = bonding_curve.config_tier == global_config.tier_id @PumpfunError::ConfigTierMismatch
     )]
     bonding_curve: Box<Account<'info, BondingCurve>>,
 
-    /// CHECK: ata of global vault
+    /// CHECK: curve vault pda which stores SOL of this curve
+    #[account(
+        mut,
+        seeds = [CURVE_VAULT.as_bytes(), bonding_curve.key().as_ref()],
+        bump = bonding_curve.curve_vault_bump,
+    )]
+    pub curve_vault: AccountInfo<'info>,
+
+    /// CHECK: ata of curve vault
     #[account(
         mut,
         seeds = [
-            global_vault.key().as_ref(),
+            curve_vault.key().as_ref(),
             anchor_spl::token::spl_token::ID.as_ref(),
             token_mint.key().as_ref(),
         ],
         bump,
         seeds::program = anchor_spl::associated_token::ID
     )]
-    global_vault_ata: AccountInfo<'info>,
+    curve_vault_ata: AccountInfo<'info>,
 
     /// CHECK: ata of admin
     #[account(
@@ -65,6 +68,26 @@ pub struct Withdraw<'info> {
     )]
     admin_ata: AccountInfo<'info>,
 
+    /// CHECK: global vault pda, update authority of the metadata
+    #[account(
+        seeds = [GLOBAL.as_bytes()],
+        bump,
+    )]
+    pub global_vault: AccountInfo<'info>,
+
+    /// CHECK: passed to token metadata program
+    #[account(
+        mut,
+        seeds = [
+            METADATA.as_bytes(),
+            metadata::ID.as_ref(),
+            token_mint.key().as_ref(),
+        ],
+        bump,
+        seeds::program = metadata::ID
+    )]
+    token_metadata_account: UncheckedAccount<'info>,
+
     #[account(address = system_program::ID)]
     system_program: Program<'info, System>,
 
@@ -73,18 +96,19 @@ pub struct Withdraw<'info> {
 
     #[account(address = associated_token::ID)]
     associated_token_program: Program<'info, AssociatedToken>,
+
+    #[account(address = metadata::ID)]
+    mpl_token_metadata_program: Program<'info, Metadata>,
 }
 
 impl<'info> Withdraw<'info> {
-pub fn process(
-    &mut self,
-    global_vault_bump:u8,
-) -> Result<()> {
+pub fn process(&mut self, global_vault_bump: u8) -> Result<WithdrawEvent> {
     let bonding_curve = &mut self.bonding_curve;
     let global_config = &mut self.global_config;
     let admin_ata = &mut self.admin_ata;
 
     require!(bonding_curve.is_completed, PumpfunError::CurveNotCompleted);
+    require!(bonding_curve.has_curve_vault, PumpfunError::CurveVaultNotMigrated);
 
     //  create admin wallet ata, if it doesn't exist
     if admin_ata.data_is_empty() {
@@ -104,21 +128,23 @@ pub fn process(
 
     // transfer sol/token to admin wallet
     let lamport_amount = bonding_curve.reserve_lamport - bonding_curve.init_lamport;
+    let bonding_curve_key = bonding_curve.key();
     let signer_seeds: &[&[&[u8]]] = &[&[
-        GLOBAL.as_bytes(),
-        &[global_vault_bump],
+        CURVE_VAULT.as_bytes(),
+        bonding_curve_key.as_ref(),
+        &[bonding_curve.curve_vault_bump],
     ]];
 
     let token_acc =
-        TokenAccount::try_deserialize(&mut &**self.global_vault_ata.try_borrow_mut_data()?)?;
-    msg!("lamports balance: {:?}", self.global_vault.lamports());
+        TokenAccount::try_deserialize(&mut &**self.curve_vault_ata.try_borrow_mut_data()?)?;
+    msg!("lamports balance: {:?}", self.curve_vault.lamports());
     msg!("token balance: {:?}", token_acc.amount);
 
     msg!("withdraw lamports: {:?}", lamport_amount);
     msg!("withdraw token: {:?}", bonding_curve.reserve_token);
 
     sol_transfer_with_signer(
-        self.global_vault.clone(),
+        self.curve_vault.clone(),
         self.admin.to_account_info(),
         &self.system_program,
         signer_seeds,
@@ -126,17 +152,50 @@ pub fn process(
     )?;
 
     token_transfer_with_signer(
-        self.global_vault_ata.clone(),
-        self.global_vault.clone(),
+        self.curve_vault_ata.clone(),
+        self.curve_vault.clone(),
         self.admin_ata.clone(),
         &self.token_program,
         signer_seeds,
         bonding_curve.reserve_token,
     )?;
 
+    let token_amount = bonding_curve.reserve_token;
     bonding_curve.update_reserves(global_config, 0, 0)?;
 
-    Ok(())
+    //  hand the metadata over or freeze it, per the tier's policy
+    let graduation_metadata = global_config.metadata_policy.on_graduation;
+    let (new_update_authority, is_mutable) = match graduation_metadata {
+        GraduationMetadata::Keep => (None, None),
+        GraduationMetadata::TransferToCreator => (Some(bonding_curve.creator), None),
+        GraduationMetadata::MakeImmutable => (None, Some(false)),
+    };
+    if graduation_metadata != GraduationMetadata::Keep {
+        metadata::update_metadata_accounts_v2(
+            CpiContext::new_with_signer(
+                self.mpl_token_metadata_program.to_account_info(),
+                metadata::UpdateMetadataAccountsV2 {
+                    metadata: self.token_metadata_account.to_account_info(),
+                    update_authority: self.global_vault.to_account_info(),
+                },
+                &[&[GLOBAL.as_bytes(), &[global_vault_bump]]],
+            ),
+            new_update_authority,
+            None,
+            None,
+            is_mutable,
+        )?;
+    }
+
+    Ok(WithdrawEvent {
+        admin: self.admin.key(),
+        mint: self.token_mint.key(),
+        bonding_curve: bonding_curve.key(),
+        lamport_amount,
+        token_amount,
+        graduation_metadata,
+        timestamp: Clock::get()?.unix_timestamp,
+    })
 }
 
 }
\ No newline at end of file
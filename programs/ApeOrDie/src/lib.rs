@@ -7,7 +7,10 @@ pub mod utils;
 
 use crate::instructions::*;
 use anchor_lang::prelude::*;
-use state::Config;
+use state::{
+    Config, ConfigChange, CurveParams, LaunchAllocation, LaunchFeeDiscount, LaunchLimits,
+    MetadataPolicy, PresaleConfig, Role,
+};
 // use crate::errors::PumpfunError;
 
 declare_id!("Ks6N2eSijgaQ6Gjpjc78M6deX8LrngprTPt5zxombdK");
@@ -18,40 +21,141 @@ pub mod ape_or_die {
 
     //  called by admin to set global config
     //  need to check the signer is authority
+    //  configs of tiers other than 0 are created by the tier 0 authority
     pub fn configure(ctx: Context<Configure>, new_config: Config) -> Result<()> {
-        // Initialize fee-related fields with defaults if not explicitly set
-        let mut config = new_config;
+        //  rejected with DevWalletNotSet if dev fees are enabled without a dev wallet
+        let event = ctx.accounts.process(new_config, ctx.bumps.config)?;
+        emit_cpi!(event);
 
-        // Set default trading fee to 1% (100 basis points) if not set
-        if config.trading_fee_bps == 0 {
-            config.trading_fee_bps = 100; // 1%
-        }
+        Ok(())
+    }
 
-        // Set default dev fee share to 50% (5000 basis points) if not set
-        if config.dev_fee_share_bps == 0 {
-            config.dev_fee_share_bps = 5000; // 50%
-        }
+    //  focused admin setters, each validates its own fields and emits old & new values
+    pub fn set_curve_params(ctx: Context<UpdateConfig>, params: CurveParams) -> Result<()> {
+        let event = ctx.accounts.set_curve_params(params)?;
+        emit_cpi!(event);
 
-        // Default to enabling dev fees
-        if config.dev_wallet == Pubkey::default() {
-            config.dev_fee_enabled = false;
-        }
+        Ok(())
+    }
+
+    pub fn set_launch_limits(ctx: Context<UpdateConfig>, params: LaunchLimits) -> Result<()> {
+        let event = ctx.accounts.set_launch_limits(params)?;
+        emit_cpi!(event);
 
-        ctx.accounts.process(config, ctx.bumps.config)
+        Ok(())
+    }
+
+    pub fn set_metadata_policy(ctx: Context<UpdateConfig>, policy: MetadataPolicy) -> Result<()> {
+        let event = ctx.accounts.set_metadata_policy(policy)?;
+        emit_cpi!(event);
+
+        Ok(())
+    }
+
+    pub fn set_launch_fee_discounts(
+        ctx: Context<UpdateConfig>,
+        discounts: Vec<LaunchFeeDiscount>,
+    ) -> Result<()> {
+        let event = ctx.accounts.set_launch_fee_discounts(discounts)?;
+        emit_cpi!(event);
+
+        Ok(())
+    }
+
+    //  fee, fee wallet and delay changes are queued and applied after `config_change_delay`
+    pub fn queue_config_change(
+        ctx: Context<QueueConfigChange>,
+        change: ConfigChange,
+    ) -> Result<()> {
+        let event = ctx.accounts.process(change)?;
+        emit_cpi!(event);
+
+        Ok(())
+    }
+
+    pub fn execute_config_change(ctx: Context<ExecuteConfigChange>) -> Result<()> {
+        let event = ctx.accounts.process()?;
+        emit_cpi!(event);
+
+        Ok(())
+    }
+
+    //  Admin can drop a queued change before it is executed
+    pub fn cancel_config_change(ctx: Context<CancelConfigChange>) -> Result<()> {
+        let event = ctx.accounts.process()?;
+        emit_cpi!(event);
+
+        Ok(())
     }
 
     //  Admin can hand over admin role
     pub fn nominate_authority(ctx: Context<NominateAuthority>, new_admin: Pubkey) -> Result<()> {
-        ctx.accounts.process(new_admin)
+        let event = ctx.accounts.process(new_admin)?;
+        emit_cpi!(event);
+
+        Ok(())
     }
 
-    //  Pending admin should accept the admin role
+    //  Pending admin should accept the admin role before the nomination expires
     pub fn accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
-        ctx.accounts.process()
+        let event = ctx.accounts.process()?;
+        emit_cpi!(event);
+
+        Ok(())
+    }
+
+    //  Admin gives up control of the config for good, fees & limits are frozen
+    //  needs an unpaused config with a migration operator, the only role that is kept
+    pub fn renounce_authority(ctx: Context<RenounceAuthority>) -> Result<()> {
+        let event = ctx.accounts.process()?;
+        emit_cpi!(event);
+
+        Ok(())
+    }
+
+    //  Admin drops the pending authority (role None) or role nomination
+    pub fn cancel_nomination(ctx: Context<CancelNomination>, role: Option<Role>) -> Result<()> {
+        let event = ctx.accounts.process(role)?;
+        emit_cpi!(event);
+
+        Ok(())
+    }
+
+    //  Admin nominates a holder for an operational role
+    pub fn nominate_role(ctx: Context<NominateRole>, role: Role, new_holder: Pubkey) -> Result<()> {
+        let event = ctx.accounts.process(role, new_holder)?;
+        emit_cpi!(event);
+
+        Ok(())
+    }
+
+    //  Pending holder should accept the role
+    pub fn accept_role(ctx: Context<AcceptRole>, role: Role) -> Result<()> {
+        let event = ctx.accounts.process(role)?;
+        emit_cpi!(event);
+
+        Ok(())
+    }
+
+    //  Admin clears the holder & nomination of a role
+    pub fn revoke_role(ctx: Context<RevokeRole>, role: Role) -> Result<()> {
+        let event = ctx.accounts.process(role)?;
+        emit_cpi!(event);
+
+        Ok(())
     }
 
-    pub fn launch(
-        ctx: Context<Launch>,
+    //  pause guardian stops or resumes launches and swaps
+    pub fn set_paused(ctx: Context<SetPaused>, paused: bool) -> Result<()> {
+        let event = ctx.accounts.process(paused)?;
+        emit_cpi!(event);
+
+        Ok(())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn launch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, Launch<'info>>,
 
         // launch config
         decimals: u8,
@@ -62,39 +166,147 @@ pub mod ape_or_die {
         name: String,
         symbol: String,
         uri: String,
+
+        //  optional allowlisted presale phase
+        presale: Option<PresaleConfig>,
+
+        //  graduation threshold, defaults to the config's curve limit
+        curve_limit: Option<u64>,
+
+        //  derive the mint from [b"mint", creator, nonce] instead of a keypair signer
+        mint_nonce: Option<u64>,
+
+        //  extra supply splits taken from the curve's share
+        //  remaining accounts: [recipient, recipient_ata] per allocation
+        //  send as a v0 transaction with an address lookup table, a legacy one runs out of space
+        allocations: Vec<LaunchAllocation>,
     ) -> Result<()> {
-        ctx.accounts.process(
+        let event = ctx.accounts.process(
             decimals,
             token_supply,
             virtual_lamport_reserves,
             name,
             symbol,
             uri,
+            presale,
+            curve_limit,
+            mint_nonce,
+            allocations,
+            ctx.remaining_accounts,
             ctx.bumps.global_vault,
-        )
+            ctx.bumps.curve_vault,
+            ctx.bumps.vesting_escrow,
+        )?;
+        emit_cpi!(event);
+
+        Ok(())
+    }
+
+    //  creator fixes name, symbol or uri of a curve that hasn't completed
+    pub fn update_metadata(
+        ctx: Context<UpdateMetadata>,
+        name: String,
+        symbol: String,
+        uri: String,
+    ) -> Result<()> {
+        let event = ctx
+            .accounts
+            .process(name, symbol, uri, ctx.bumps.global_vault)?;
+        emit_cpi!(event);
+
+        Ok(())
+    }
+
+    //  team wallet claims the vested part of a curve's team allocation
+    pub fn claim_vested(ctx: Context<ClaimVested>) -> Result<()> {
+        let event = ctx.accounts.process()?;
+        emit_cpi!(event);
+
+        Ok(())
     }
 
     //  amount - swap amount
     //  direction - 0: buy, 1: sell
+    //  presale_proof - merkle proof of the buyer, only checked for buys during the presale
     pub fn swap(
         ctx: Context<Swap>,
         amount: u64,
         direction: u8,
         minimum_receive_amount: u64,
         deadline: i64,
+        presale_proof: Vec<[u8; 32]>,
     ) -> Result<u64> {
-        ctx.accounts.process(
+        let (swap_event, complete_event) = ctx.accounts.process(
             amount,
             direction,
             minimum_receive_amount,
             deadline,
-            ctx.bumps.global_vault,
-        )
+            presale_proof,
+        )?;
+        let amount_out = swap_event.amount_out;
+
+        emit_cpi!(swap_event);
+        if let Some(complete_event) = complete_event {
+            emit_cpi!(complete_event);
+        }
+
+        Ok(amount_out)
+    }
+
+    //  rewrites the tier 0 config deployed before the layout grew, callable by anyone
+    //  appended fields get defaults that keep the legacy behaviour
+    pub fn migrate_config(ctx: Context<MigrateConfig>) -> Result<()> {
+        let event = ctx.accounts.process()?;
+        emit_cpi!(event);
+
+        Ok(())
+    }
+
+    //  moves SOL & tokens of a curve launched before per-curve vaults
+    //  out of the global vault, callable by anyone
+    pub fn migrate_curve_vault(ctx: Context<MigrateCurveVault>) -> Result<()> {
+        let event = ctx
+            .accounts
+            .process(ctx.bumps.global_vault, ctx.bumps.curve_vault)?;
+        emit_cpi!(event);
+
+        Ok(())
+    }
+
+    //  creates the candle account of a curve launched before price history, callable by anyone
+    pub fn init_candles(ctx: Context<InitCandles>) -> Result<()> {
+        ctx.accounts.process()
     }
 
-    //  admin withdraws token & sol
+    //  creates the registry of a creator who launched before registries, callable by anyone
+    //  swaps on their curves update its stats once it exists
+    pub fn init_creator_registry(ctx: Context<InitCreatorRegistry>) -> Result<()> {
+        ctx.accounts.process()
+    }
+
+    //  pays the swap fees accrued in a curve vault to the team & dev wallets, callable by anyone
+    pub fn sweep_fees(ctx: Context<SweepFees>) -> Result<()> {
+        let event = ctx.accounts.process()?;
+        emit_cpi!(event);
+
+        Ok(())
+    }
+
+    //  permissionless proof-of-reserves check for the curves in remaining accounts
+    pub fn audit<'info>(ctx: Context<'_, '_, 'info, 'info, Audit<'info>>) -> Result<()> {
+        let event = ctx.accounts.process(ctx.remaining_accounts)?;
+        emit_cpi!(event);
+
+        Ok(())
+    }
+
+    //  migration operator withdraws token & sol
     //  migration should be done on backend
+    //  the metadata update authority is handed off per the config's graduation policy
     pub fn withdraw(ctx: Context<Withdraw>) -> Result<()> {
-        ctx.accounts.process(ctx.bumps.global_vault)
+        let event = ctx.accounts.process(ctx.bumps.global_vault)?;
+        emit_cpi!(event);
+
+        Ok(())
     }
 }
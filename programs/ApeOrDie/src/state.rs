@@ -1,15 +1,24 @@
-use crate::constants::LAMPORT_DECIMALS;
+use crate::constants::{
+    CANDLE_COUNT, DEFAULT_NOMINATION_VALIDITY, LAMPORT_DECIMALS, MAX_CONFIG_CHANGE_DELAY,
+    MAX_LAUNCH_ALLOCATIONS, MAX_LAUNCH_FEE_DISCOUNTS, MAX_LAUNCH_FEE_LAMPORTS,
+    MAX_LAUNCH_RATE_WINDOW, MAX_NAME_LENGTH, MAX_NOMINATION_VALIDITY, MAX_SYMBOL_LENGTH,
+    MAX_URI_LENGTH, MAX_VESTING_PERIOD, RECENT_LAUNCH_COUNT,
+};
 use crate::errors::*;
-use crate::events::CompleteEvent;
+use crate::events::SwapEvent;
 use crate::utils::*;
 use anchor_lang::{prelude::*, AnchorDeserialize, AnchorSerialize};
 use anchor_spl::token::Mint;
 use anchor_spl::token::Token;
 use core::fmt::Debug;
+use core::ops::{Rem, Sub};
 
 pub const FEE_BASIS_POINTS: u128 = 10000;
 pub const HUNDRED_PERCENT_BPS: u128 = 10000;
+//  prices are lamports per token base unit, scaled by 1e12
+pub const PRICE_SCALE: u128 = 1_000_000_000_000;
 #[account]
+#[derive(Debug, PartialEq)]
 pub struct Config {
     pub authority: Pubkey,
     //  use this for 2 step ownership transfer
@@ -20,7 +29,7 @@ pub struct Config {
     // New field for developer wallet address
     pub dev_wallet: Pubkey,
 
-    pub init_bonding_curve: f64, // bonding curve init percentage. The remaining amount is sent to team wallet for distribution to agent
+    pub init_bonding_curve: f64, // bonding curve init percentage, launch allocations come out of it. The remaining amount vests to the team wallet
 
     pub platform_buy_fee: u128, //  platform fee percentage
     pub platform_sell_fee: u128,
@@ -35,42 +44,822 @@ pub struct Config {
     pub lamport_amount_config: AmountConfig<u64>,
     pub token_supply_config: AmountConfig<u64>,
     pub token_decimals_config: AmountConfig<u8>,
+
+    //  seconds between `queue_config_change` and `execute_config_change`
+    pub config_change_delay: i64,
+
+    //  operational roles, the authority holds all of them implicitly
+    pub fee_admin: RoleAssignment,
+    pub pause_guardian: RoleAssignment,
+    pub migration_operator: RoleAssignment,
+    pub launch_moderator: RoleAssignment,
+
+    //  set by the pause guardian, blocks launches and swaps
+    pub paused: bool,
+
+    //  when `pending_authority` was nominated
+    pub pending_authority_nominated_at: i64,
+    //  seconds a nomination of the authority or a role can be accepted for
+    pub nomination_validity: i64,
+
+    //  launch preset, part of the config address
+    pub tier_id: u8,
+
+    //  bounds of the curve limit a creator can pick at launch
+    pub curve_limit_config: AmountConfig<u64>,
+
+    //  name, symbol & uri rules checked at launch
+    pub metadata_policy: MetadataPolicy,
+
+    //  charged to the creator on launch, sent to the team wallet
+    pub launch_fee_lamports: u64,
+    //  allowlisted creators pay a reduced launch fee
+    pub launch_fee_discounts: Vec<LaunchFeeDiscount>,
+
+    //  launches allowed per creator per rolling window
+    pub launch_rate_limit: LaunchRateLimit,
+
+    //  unlock schedule of the team allocation
+    pub team_vesting: VestingSchedule,
+
+    //  caps on the supply allocations a creator can request at launch
+    pub allocation_limits: AllocationLimits,
+}
+
+//  Config layout before `config_change_delay` and the fields after it were appended
+//  the deployed config is read with it by `migrate_config`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Debug)]
+pub struct LegacyConfig {
+    pub authority: Pubkey,
+    pub pending_authority: Pubkey,
+    pub team_wallet: Pubkey,
+    pub dev_wallet: Pubkey,
+    pub init_bonding_curve: f64,
+    pub platform_buy_fee: u128,
+    pub platform_sell_fee: u128,
+    pub trading_fee_bps: u16,
+    pub dev_fee_share_bps: u16,
+    pub dev_fee_enabled: bool,
+    pub curve_limit: u64,
+    pub lamport_amount_config: AmountConfig<u64>,
+    pub token_supply_config: AmountConfig<u64>,
+    pub token_decimals_config: AmountConfig<u8>,
+}
+
+impl LegacyConfig {
+    //  appended fields get the values that keep the legacy behaviour
+    pub fn migrate(self) -> Config {
+        Config {
+            authority: self.authority,
+            pending_authority: self.pending_authority,
+            team_wallet: self.team_wallet,
+            dev_wallet: self.dev_wallet,
+            init_bonding_curve: self.init_bonding_curve,
+            platform_buy_fee: self.platform_buy_fee,
+            platform_sell_fee: self.platform_sell_fee,
+            trading_fee_bps: self.trading_fee_bps,
+            dev_fee_share_bps: self.dev_fee_share_bps,
+            dev_fee_enabled: self.dev_fee_enabled,
+            curve_limit: self.curve_limit,
+            lamport_amount_config: self.lamport_amount_config,
+            token_supply_config: self.token_supply_config,
+            token_decimals_config: self.token_decimals_config,
+            config_change_delay: 0,
+            fee_admin: RoleAssignment::default(),
+            pause_guardian: RoleAssignment::default(),
+            migration_operator: RoleAssignment::default(),
+            launch_moderator: RoleAssignment::default(),
+            paused: false,
+            //  a pending nomination without a timestamp is already expired
+            pending_authority_nominated_at: 0,
+            nomination_validity: DEFAULT_NOMINATION_VALIDITY,
+            tier_id: 0,
+            //  creators can only use the config's curve limit, as before
+            curve_limit_config: AmountConfig::Enum(vec![self.curve_limit]),
+            metadata_policy: MetadataPolicy {
+                max_name_len: MAX_NAME_LENGTH as u8,
+                max_symbol_len: MAX_SYMBOL_LENGTH as u8,
+                max_uri_len: MAX_URI_LENGTH as u16,
+                allowed_uri_prefixes: vec!["https://".into(), "ipfs://".into(), "ar://".into()],
+                symbol_charset: None,
+                on_graduation: GraduationMetadata::Keep,
+            },
+            launch_fee_lamports: 0,
+            launch_fee_discounts: vec![],
+            launch_rate_limit: LaunchRateLimit { max_launches: 0, window: 0 },
+            //  the team allocation unlocks at launch, as before
+            team_vesting: VestingSchedule { cliff: 0, duration: 0 },
+            allocation_limits: AllocationLimits {
+                max_allocations: 0,
+                max_allocation_bps: 0,
+                min_curve_bps: 0,
+            },
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Role {
+    FeeAdmin,          //  queues fee changes, sets curve params
+    PauseGuardian,     //  pauses launches and swaps
+    MigrationOperator, //  withdraws completed curves for migration
+    LaunchModerator,   //  sets launch limits
+}
+
+//  role holder with a 2 step nomination, like authority & pending_authority
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct RoleAssignment {
+    pub holder: Pubkey,
+    pub pending: Pubkey,
+    pub nominated_at: i64,
+}
+
+//  fee fields of Config, changed through the timelock
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug, InitSpace)]
+pub struct FeeParams {
+    pub platform_buy_fee: u128,
+    pub platform_sell_fee: u128,
+    pub trading_fee_bps: u16,
+    pub dev_fee_share_bps: u16,
+    pub dev_fee_enabled: bool,
+    pub launch_fee_lamports: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug, InitSpace)]
+pub struct LaunchFeeDiscount {
+    pub creator: Pubkey,
+    pub discount_bps: u16, // 10000 exempts the creator
+}
+
+//  fee recipients of Config, changed through the timelock
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug, InitSpace)]
+pub struct FeeWalletParams {
+    pub team_wallet: Pubkey,
+    pub dev_wallet: Pubkey,
+}
+
+//  curve shape fields of Config, updated through `set_curve_params`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Debug)]
+pub struct CurveParams {
+    pub init_bonding_curve: f64,
+    pub curve_limit: u64,
+    pub team_vesting: VestingSchedule,
+}
+
+//  nothing unlocks before the cliff, then linear over `duration` from the launch
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub struct VestingSchedule {
+    pub cliff: i64,    // seconds
+    pub duration: i64, // seconds, 0 unlocks everything at the cliff
+}
+
+//  launch argument limits of Config, updated through `set_launch_limits`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct LaunchLimits {
+    pub lamport_amount_config: AmountConfig<u64>,
+    pub token_supply_config: AmountConfig<u64>,
+    pub token_decimals_config: AmountConfig<u8>,
+    pub curve_limit_config: AmountConfig<u64>,
+    pub launch_rate_limit: LaunchRateLimit,
+    pub allocation_limits: AllocationLimits,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct AllocationLimits {
+    pub max_allocations: u8,     // 0 disables allocations
+    pub max_allocation_bps: u16, // of the token supply, per recipient
+    pub min_curve_bps: u16,      // of the token supply, left on the curve
+}
+
+//  part of the supply minted to `recipient` at launch, taken from the curve's share
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct LaunchAllocation {
+    pub recipient: Pubkey,
+    pub bps: u16,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct LaunchRateLimit {
+    pub max_launches: u8, // 0 disables the limit
+    pub window: i64,      // seconds
+}
+
+//  token metadata rules, updated through `set_metadata_policy`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct MetadataPolicy {
+    pub max_name_len: u8,
+    pub max_symbol_len: u8,
+    pub max_uri_len: u16,
+    pub allowed_uri_prefixes: Vec<String>, // e.g. "https://", "ipfs://"
+    pub symbol_charset: Option<String>,    // characters a symbol may use, any if None
+    pub on_graduation: GraduationMetadata, // applied when a completed curve is withdrawn
+}
+
+//  what happens to the metadata update authority once a curve graduates
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum GraduationMetadata {
+    #[default]
+    Keep, //  global vault stays update authority
+    TransferToCreator,
+    MakeImmutable,
+}
+
+impl Config {
+    pub fn fee_params(&self) -> FeeParams {
+        FeeParams {
+            platform_buy_fee: self.platform_buy_fee,
+            platform_sell_fee: self.platform_sell_fee,
+            trading_fee_bps: self.trading_fee_bps,
+            dev_fee_share_bps: self.dev_fee_share_bps,
+            dev_fee_enabled: self.dev_fee_enabled,
+            launch_fee_lamports: self.launch_fee_lamports,
+        }
+    }
+
+    pub fn set_fee_params(&mut self, params: FeeParams) {
+        self.platform_buy_fee = params.platform_buy_fee;
+        self.platform_sell_fee = params.platform_sell_fee;
+        self.trading_fee_bps = params.trading_fee_bps;
+        self.dev_fee_share_bps = params.dev_fee_share_bps;
+        self.dev_fee_enabled = params.dev_fee_enabled;
+        self.launch_fee_lamports = params.launch_fee_lamports;
+    }
+
+    //  launch fee after the creator's discount
+    pub fn launch_fee_discount_bps(&self, creator: &Pubkey) -> u16 {
+        self.launch_fee_discounts
+            .iter()
+            .find(|discount| discount.creator == *creator)
+            .map_or(0, |discount| discount.discount_bps)
+    }
+
+    pub fn launch_fee_for(&self, creator: &Pubkey) -> u64 {
+        let discount_bps = self.launch_fee_discount_bps(creator) as u128;
+
+        (self.launch_fee_lamports as u128 * (HUNDRED_PERCENT_BPS - discount_bps)
+            / HUNDRED_PERCENT_BPS) as u64
+    }
+
+    //  updates an existing discount, 0 removes it
+    //  never adds one, the account isn't grown when a queued change is executed
+    pub fn set_launch_fee_discount(&mut self, discount: &LaunchFeeDiscount) {
+        if discount.discount_bps == 0 {
+            self.launch_fee_discounts
+                .retain(|existing| existing.creator != discount.creator);
+        } else if let Some(existing) = self
+            .launch_fee_discounts
+            .iter_mut()
+            .find(|existing| existing.creator == discount.creator)
+        {
+            existing.discount_bps = discount.discount_bps;
+        }
+    }
+
+    //  true if no current discount is lowered or removed by `discounts`
+    pub fn keeps_launch_fee_discounts(&self, discounts: &[LaunchFeeDiscount]) -> bool {
+        self.launch_fee_discounts.iter().all(|existing| {
+            discounts.iter().any(|discount| {
+                discount.creator == existing.creator
+                    && discount.discount_bps >= existing.discount_bps
+            })
+        })
+    }
+
+    pub fn fee_wallet_params(&self) -> FeeWalletParams {
+        FeeWalletParams {
+            team_wallet: self.team_wallet,
+            dev_wallet: self.dev_wallet,
+        }
+    }
+
+    pub fn set_fee_wallet_params(&mut self, params: FeeWalletParams) {
+        self.team_wallet = params.team_wallet;
+        self.dev_wallet = params.dev_wallet;
+    }
+
+    pub fn curve_params(&self) -> CurveParams {
+        CurveParams {
+            init_bonding_curve: self.init_bonding_curve,
+            curve_limit: self.curve_limit,
+            team_vesting: self.team_vesting,
+        }
+    }
+
+    pub fn set_curve_params(&mut self, params: CurveParams) {
+        self.init_bonding_curve = params.init_bonding_curve;
+        self.curve_limit = params.curve_limit;
+        self.team_vesting = params.team_vesting;
+    }
+
+    pub fn launch_limits(&self) -> LaunchLimits {
+        LaunchLimits {
+            lamport_amount_config: self.lamport_amount_config.clone(),
+            token_supply_config: self.token_supply_config.clone(),
+            token_decimals_config: self.token_decimals_config.clone(),
+            curve_limit_config: self.curve_limit_config.clone(),
+            launch_rate_limit: self.launch_rate_limit,
+            allocation_limits: self.allocation_limits,
+        }
+    }
+
+    pub fn set_launch_limits(&mut self, params: LaunchLimits) {
+        self.lamport_amount_config = params.lamport_amount_config;
+        self.token_supply_config = params.token_supply_config;
+        self.token_decimals_config = params.token_decimals_config;
+        self.curve_limit_config = params.curve_limit_config;
+        self.launch_rate_limit = params.launch_rate_limit;
+        self.allocation_limits = params.allocation_limits;
+    }
+
+    pub fn role(&self, role: Role) -> &RoleAssignment {
+        match role {
+            Role::FeeAdmin => &self.fee_admin,
+            Role::PauseGuardian => &self.pause_guardian,
+            Role::MigrationOperator => &self.migration_operator,
+            Role::LaunchModerator => &self.launch_moderator,
+        }
+    }
+
+    pub fn role_mut(&mut self, role: Role) -> &mut RoleAssignment {
+        match role {
+            Role::FeeAdmin => &mut self.fee_admin,
+            Role::PauseGuardian => &mut self.pause_guardian,
+            Role::MigrationOperator => &mut self.migration_operator,
+            Role::LaunchModerator => &mut self.launch_moderator,
+        }
+    }
+
+    pub fn roles(&self) -> [RoleAssignment; 4] {
+        [
+            self.fee_admin,
+            self.pause_guardian,
+            self.migration_operator,
+            self.launch_moderator,
+        ]
+    }
+
+    pub fn has_role(&self, role: Role, key: &Pubkey) -> bool {
+        let holder = self.role(role).holder;
+        *key == self.authority || (holder != Pubkey::default() && holder == *key)
+    }
+
+    //  tier 0 keeps the original `[CONFIG]` address
+    pub fn tier_seed(tier_id: &u8) -> &[u8] {
+        if *tier_id == 0 {
+            &[]
+        } else {
+            core::slice::from_ref(tier_id)
+        }
+    }
+
+    //  nobody can sign for the all-zero key, so admin-only instructions are disabled for good
+    pub fn is_renounced(&self) -> bool {
+        self.authority == Pubkey::default()
+    }
+
+    //  last second a nomination made at `nominated_at` can be accepted
+    pub fn nomination_expiry(&self, nominated_at: i64) -> i64 {
+        nominated_at.saturating_add(self.nomination_validity)
+    }
+
+    //  fee changes belong to the fee admin, fee wallets & the delay stay with the authority
+    pub fn can_change(&self, change: &ConfigChange, key: &Pubkey) -> bool {
+        match change {
+            ConfigChange::Fees(_) | ConfigChange::LaunchFeeDiscount(_) => {
+                self.has_role(Role::FeeAdmin, key)
+            }
+            ConfigChange::FeeWallets(_) | ConfigChange::Delay(_) => *key == self.authority,
+        }
+    }
+}
+
+impl Config {
+    //  checked on every update, the setters validate the whole config after applying their fields
+    pub fn validate(&self) -> Result<()> {
+        self.fee_params().validate()?;
+        self.fee_wallet_params().validate()?;
+        self.curve_params().validate()?;
+        self.launch_limits().validate()?;
+        self.metadata_policy.validate()?;
+        validate_launch_fee_discounts(&self.launch_fee_discounts)?;
+
+        require!(
+            !self.dev_fee_enabled || self.dev_wallet != Pubkey::default(),
+            PumpfunError::DevWalletNotSet
+        );
+
+        require!(
+            (0..=MAX_CONFIG_CHANGE_DELAY).contains(&self.config_change_delay),
+            PumpfunError::InvalidConfigChangeDelay
+        );
+
+        require!(
+            (1..=MAX_NOMINATION_VALIDITY).contains(&self.nomination_validity),
+            PumpfunError::InvalidNominationValidity
+        );
+
+        //  launches without a curve limit use the default one
+        self.curve_limit_config.validate(&self.curve_limit)?;
+
+        //  the curve would complete on launch otherwise
+        match self.lamport_amount_config.max_value() {
+            Some(max_lamports) if self.curve_limit > *max_lamports => Ok(()),
+            max_lamports => {
+                msg!(
+                    "curve limit {:?} must exceed max virtual lamports {:?}",
+                    self.curve_limit,
+                    max_lamports
+                );
+                err!(PumpfunError::CurveLimitTooLow)
+            }
+        }
+    }
+}
+
+//  config changes that have to wait for `config_change_delay`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug, InitSpace)]
+pub enum ConfigChange {
+    Fees(FeeParams),
+    FeeWallets(FeeWalletParams),
+    Delay(i64),
+    //  lowers or removes (discount_bps 0) an existing discount, raising one doesn't need the timelock
+    LaunchFeeDiscount(LaunchFeeDiscount),
+}
+
+impl ConfigChange {
+    //  current values of the fields this change touches
+    pub fn current(&self, config: &Config) -> Self {
+        match self {
+            Self::Fees(_) => Self::Fees(config.fee_params()),
+            Self::FeeWallets(_) => Self::FeeWallets(config.fee_wallet_params()),
+            Self::Delay(_) => Self::Delay(config.config_change_delay),
+            Self::LaunchFeeDiscount(discount) => Self::LaunchFeeDiscount(LaunchFeeDiscount {
+                creator: discount.creator,
+                discount_bps: config.launch_fee_discount_bps(&discount.creator),
+            }),
+        }
+    }
+
+    pub fn apply(&self, config: &mut Config) {
+        match self {
+            Self::Fees(params) => config.set_fee_params(params.clone()),
+            Self::FeeWallets(params) => config.set_fee_wallet_params(params.clone()),
+            Self::Delay(delay) => config.config_change_delay = *delay,
+            Self::LaunchFeeDiscount(discount) => config.set_launch_fee_discount(discount),
+        }
+    }
+}
+
+//  queued config change, one per config
+#[account]
+#[derive(InitSpace)]
+pub struct PendingConfigChange {
+    pub config: Pubkey,
+    pub change: ConfigChange,
+    pub eta: i64,
+}
+
+impl FeeParams {
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.platform_buy_fee <= FEE_BASIS_POINTS && self.platform_sell_fee <= FEE_BASIS_POINTS,
+            PumpfunError::PlatformFeeTooHigh
+        );
+        require!(
+            self.trading_fee_bps as u128 + self.dev_fee_share_bps as u128 <= HUNDRED_PERCENT_BPS,
+            PumpfunError::FeeSplitTooHigh
+        );
+        require!(
+            self.launch_fee_lamports <= MAX_LAUNCH_FEE_LAMPORTS,
+            PumpfunError::LaunchFeeTooHigh
+        );
+        Ok(())
+    }
+}
+
+pub fn validate_launch_fee_discounts(discounts: &[LaunchFeeDiscount]) -> Result<()> {
+    require!(
+        discounts.len() <= MAX_LAUNCH_FEE_DISCOUNTS,
+        PumpfunError::InvalidLaunchFeeDiscounts
+    );
+    for (index, discount) in discounts.iter().enumerate() {
+        require!(
+            discount.discount_bps as u128 <= HUNDRED_PERCENT_BPS,
+            PumpfunError::InvalidLaunchFeeDiscounts
+        );
+        //  one entry per creator
+        require!(
+            discounts[..index]
+                .iter()
+                .all(|other| other.creator != discount.creator),
+            PumpfunError::InvalidLaunchFeeDiscounts
+        );
+    }
+    Ok(())
+}
+
+impl FeeWalletParams {
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.team_wallet != Pubkey::default(),
+            PumpfunError::IncorrectTeamWallet
+        );
+        Ok(())
+    }
+}
+
+impl CurveParams {
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            (0.0..=100.0).contains(&self.init_bonding_curve),
+            PumpfunError::InvalidInitBondingCurve
+        );
+        require!(self.curve_limit > 0, PumpfunError::ValueTooSmall);
+        require!(
+            (0..=MAX_VESTING_PERIOD).contains(&self.team_vesting.cliff)
+                && (0..=MAX_VESTING_PERIOD).contains(&self.team_vesting.duration),
+            PumpfunError::InvalidVestingSchedule
+        );
+        Ok(())
+    }
+}
+
+impl LaunchRateLimit {
+    pub fn validate(&self) -> Result<()> {
+        //  older launches are not remembered
+        require!(
+            self.max_launches as usize <= RECENT_LAUNCH_COUNT,
+            PumpfunError::InvalidLaunchRateLimit
+        );
+        require!(
+            self.max_launches == 0 || (1..=MAX_LAUNCH_RATE_WINDOW).contains(&self.window),
+            PumpfunError::InvalidLaunchRateLimit
+        );
+        Ok(())
+    }
+}
+
+impl AllocationLimits {
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.max_allocations as usize <= MAX_LAUNCH_ALLOCATIONS
+                && self.max_allocation_bps as u128 <= FEE_BASIS_POINTS
+                && self.min_curve_bps as u128 <= FEE_BASIS_POINTS,
+            PumpfunError::InvalidAllocationLimits
+        );
+        Ok(())
+    }
+
+    //  checks the requested allocations and returns their total bps
+    pub fn check(&self, allocations: &[LaunchAllocation]) -> Result<u16> {
+        require!(
+            allocations.len() <= self.max_allocations as usize,
+            PumpfunError::TooManyAllocations
+        );
+
+        let mut total_bps: u16 = 0;
+        for (i, allocation) in allocations.iter().enumerate() {
+            require!(
+                allocation.bps > 0 && allocation.bps <= self.max_allocation_bps,
+                PumpfunError::AllocationTooLarge
+            );
+            require!(
+                !allocations[..i].iter().any(|a| a.recipient == allocation.recipient),
+                PumpfunError::DuplicateAccount
+            );
+            total_bps = total_bps
+                .checked_add(allocation.bps)
+                .ok_or(PumpfunError::OverflowOrUnderflowOccurred)?;
+        }
+
+        Ok(total_bps)
+    }
+}
+
+impl MetadataPolicy {
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            (1..=MAX_NAME_LENGTH).contains(&(self.max_name_len as usize))
+                && (1..=MAX_SYMBOL_LENGTH).contains(&(self.max_symbol_len as usize))
+                && (1..=MAX_URI_LENGTH).contains(&(self.max_uri_len as usize)),
+            PumpfunError::InvalidMetadataPolicy
+        );
+        //  an empty prefix would allow any uri
+        require!(
+            !self.allowed_uri_prefixes.is_empty()
+                && self.allowed_uri_prefixes.iter().all(|prefix| !prefix.is_empty()),
+            PumpfunError::InvalidMetadataPolicy
+        );
+        require!(
+            !matches!(&self.symbol_charset, Some(charset) if charset.is_empty()),
+            PumpfunError::InvalidMetadataPolicy
+        );
+        Ok(())
+    }
+
+    pub fn check(&self, name: &str, symbol: &str, uri: &str) -> Result<()> {
+        if name.len() > self.max_name_len as usize {
+            msg!("name is {} bytes, at most {} allowed", name.len(), self.max_name_len);
+            return err!(PumpfunError::NameTooLong);
+        }
+        if symbol.len() > self.max_symbol_len as usize {
+            msg!("symbol is {} bytes, at most {} allowed", symbol.len(), self.max_symbol_len);
+            return err!(PumpfunError::SymbolTooLong);
+        }
+        if uri.len() > self.max_uri_len as usize {
+            msg!("uri is {} bytes, at most {} allowed", uri.len(), self.max_uri_len);
+            return err!(PumpfunError::UriTooLong);
+        }
+
+        if !self
+            .allowed_uri_prefixes
+            .iter()
+            .any(|prefix| uri.starts_with(prefix.as_str()))
+        {
+            msg!("uri must start with one of: {:?}", self.allowed_uri_prefixes);
+            return err!(PumpfunError::UriSchemeNotAllowed);
+        }
+
+        if let Some(charset) = &self.symbol_charset {
+            if let Some(invalid) = symbol.chars().find(|c| !charset.contains(*c)) {
+                msg!("symbol character {:?} is not in {:?}", invalid, charset);
+                return err!(PumpfunError::InvalidSymbolCharacter);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl LaunchLimits {
+    pub fn validate(&self) -> Result<()> {
+        self.lamport_amount_config.check()?;
+        self.token_supply_config.check()?;
+        self.token_decimals_config.check()?;
+        self.curve_limit_config.check()?;
+        self.launch_rate_limit.validate()?;
+        self.allocation_limits.validate()?;
+
+        // Decimal overflow check
+        match self.token_decimals_config.max_value() {
+            Some(max_decimals) if *max_decimals < 20 => Ok(()),
+            _ => err!(PumpfunError::DecimalOverflow),
+        }
+    }
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
 pub enum AmountConfig<T: PartialEq + PartialOrd + Debug> {
     Range { min: Option<T>, max: Option<T> },
     Enum(Vec<T>),
+    //  min, min + step, ... up to max
+    Step { min: T, max: T, step: T },
+    //  value has to satisfy every rule
+    All(Vec<AmountRule<T>>),
+}
+
+//  the non-combined configs, borsh derives can't nest `AmountConfig` in itself
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub enum AmountRule<T: PartialEq + PartialOrd + Debug> {
+    Range { min: Option<T>, max: Option<T> },
+    Enum(Vec<T>),
+    Step { min: T, max: T, step: T },
+}
+
+//  borrowed view shared by `AmountConfig` and `AmountRule`
+enum AmountBound<'a, T> {
+    Range { min: Option<&'a T>, max: Option<&'a T> },
+    Enum(&'a [T]),
+    Step { min: &'a T, max: &'a T, step: &'a T },
 }
 
-impl<T: PartialEq + PartialOrd + Debug> AmountConfig<T> {
+impl<T> AmountConfig<T>
+where
+    T: PartialEq + PartialOrd + Debug + Copy + Default + Sub<Output = T> + Rem<Output = T>,
+{
+    fn bound(&self) -> Option<AmountBound<'_, T>> {
+        Some(match self {
+            Self::Range { min, max } => AmountBound::Range {
+                min: min.as_ref(),
+                max: max.as_ref(),
+            },
+            Self::Enum(options) => AmountBound::Enum(options),
+            Self::Step { min, max, step } => AmountBound::Step { min, max, step },
+            Self::All(_) => return None,
+        })
+    }
+
+    //  upper bound of the allowed values, None if unbounded
+    pub fn max_value(&self) -> Option<&T> {
+        match self {
+            Self::All(rules) => rules
+                .iter()
+                .filter_map(|rule| rule.bound().max_value())
+                .reduce(|min, value| if value < min { value } else { min }),
+            _ => self.bound()?.max_value(),
+        }
+    }
+
+    //  rejects configs that no value can satisfy
+    pub fn check(&self) -> Result<()> {
+        match self {
+            Self::All(rules) if rules.is_empty() => err!(PumpfunError::EmptyAmountConfig),
+            Self::All(rules) => rules.iter().try_for_each(|rule| rule.bound().check()),
+            _ => self.bound().map_or(Ok(()), |bound| bound.check()),
+        }
+    }
+
     pub fn validate(&self, value: &T) -> Result<()> {
         match self {
-            Self::Range { min, max } => {
-                if let Some(min) = min {
-                    if value < min {
-                        msg!("value {value:?} too small, expected at least {min:?}");
-                        return Err(ValueTooSmall.into());
-                    }
-                }
-                if let Some(max) = max {
-                    if value > max {
-                        msg!("value {value:?} too large, expected at most {max:?}");
-                        return Err(ValueTooLarge.into());
-                    }
-                }
+            Self::All(rules) => rules
+                .iter()
+                .try_for_each(|rule| rule.bound().validate(value)),
+            _ => self.bound().map_or(Ok(()), |bound| bound.validate(value)),
+        }
+    }
+}
 
-                Ok(())
+impl<T: PartialEq + PartialOrd + Debug> AmountRule<T> {
+    fn bound(&self) -> AmountBound<'_, T> {
+        match self {
+            Self::Range { min, max } => AmountBound::Range {
+                min: min.as_ref(),
+                max: max.as_ref(),
+            },
+            Self::Enum(options) => AmountBound::Enum(options),
+            Self::Step { min, max, step } => AmountBound::Step { min, max, step },
+        }
+    }
+}
+
+impl<'a, T> AmountBound<'a, T>
+where
+    T: PartialEq + PartialOrd + Debug + Copy + Default + Sub<Output = T> + Rem<Output = T>,
+{
+    fn max_value(&self) -> Option<&'a T> {
+        match *self {
+            Self::Range { max, .. } => max,
+            Self::Enum(options) => options
+                .iter()
+                .reduce(|max, value| if value > max { value } else { max }),
+            Self::Step { max, .. } => Some(max),
+        }
+    }
+
+    fn check(&self) -> Result<()> {
+        match *self {
+            Self::Range {
+                min: Some(min),
+                max: Some(max),
+            }
+            | Self::Step { min, max, .. }
+                if min > max =>
+            {
+                msg!("range minimum {min:?} is above maximum {max:?}");
+                err!(PumpfunError::InvalidAmountRange)
             }
+            Self::Step { step, .. } if *step == T::default() => {
+                err!(PumpfunError::InvalidAmountStep)
+            }
+            Self::Enum([]) => err!(PumpfunError::EmptyAmountConfig),
+            _ => Ok(()),
+        }
+    }
+
+    fn validate(&self, value: &T) -> Result<()> {
+        let (min, max) = match *self {
+            Self::Range { min, max } => (min, max),
+            Self::Step { min, max, .. } => (Some(min), Some(max)),
             Self::Enum(options) => {
                 if options.contains(value) {
-                    Ok(())
-                } else {
-                    msg!("invalid value {value:?}, expected one of: {options:?}");
-                    Err(ValueInvalid.into())
+                    return Ok(());
                 }
+                msg!("invalid value {value:?}, expected one of: {options:?}");
+                return Err(ValueInvalid.into());
+            }
+        };
+
+        if let Some(min) = min {
+            if value < min {
+                msg!("value {value:?} too small, expected at least {min:?}");
+                return Err(ValueTooSmall.into());
+            }
+        }
+        if let Some(max) = max {
+            if value > max {
+                msg!("value {value:?} too large, expected at most {max:?}");
+                return Err(ValueTooLarge.into());
+            }
+        }
+
+        if let Self::Step { min, step, .. } = *self {
+            if (*value - *min) % *step != T::default() {
+                msg!("value {value:?} is not {min:?} plus a multiple of {step:?}");
+                return Err(ValueNotOnStep.into());
             }
         }
+
+        Ok(())
     }
 }
 
@@ -84,6 +873,226 @@ pub struct BondingCurve {
     pub reserve_token: u64,
     pub curve_limit: u64, // Store curve limit at launch time
     pub is_completed: bool,
+
+    //  allowlisted presale phase, disabled when the root is all zeroes
+    pub presale_merkle_root: [u8; 32],
+    pub presale_end_ts: i64,
+    pub presale_wallet_cap: u64, // max lamports each allowlisted wallet can spend
+
+    //  SOL and tokens are held by a vault pda seeded per curve
+    //  curves launched before this are migrated through `migrate_curve_vault`
+    pub has_curve_vault: bool,
+    pub curve_vault_bump: u8,
+
+    //  time weighted price accumulator, see `twap`
+    pub price_cumulative: u128, // sum of spot_price * seconds, wraps on overflow
+    pub last_price_update_ts: i64,
+
+    pub config_tier: u8, // tier of the config the curve was launched with
+
+    //  swap fees held in the curve vault, paid out by `sweep_fees`
+    pub accrued_platform_fee: u64,
+    pub accrued_dev_fee: u64,
+
+    //  set once the candle account exists, swaps have to record into it from then on
+    pub has_candles: bool,
+}
+
+impl BondingCurve {
+    //  spot price from the reserves, see PRICE_SCALE
+    pub fn spot_price(&self) -> u64 {
+        if self.reserve_token == 0 {
+            return 0;
+        }
+        let price = (self.reserve_lamport as u128)
+            .saturating_mul(PRICE_SCALE)
+            .checked_div(self.reserve_token as u128)
+            .unwrap_or_default();
+        u64::try_from(price).unwrap_or(u64::MAX)
+    }
+
+    //  accumulator value at `timestamp`, without writing to the account
+    pub fn price_cumulative_at(&self, timestamp: i64) -> u128 {
+        let elapsed = timestamp.saturating_sub(self.last_price_update_ts).max(0) as u128;
+        self.price_cumulative
+            .wrapping_add((self.spot_price() as u128).wrapping_mul(elapsed))
+    }
+
+    //  called before the reserves change so the elapsed time is priced at the old reserves
+    pub fn accumulate_price(&mut self, timestamp: i64) {
+        if self.last_price_update_ts != 0 {
+            self.price_cumulative = self.price_cumulative_at(timestamp);
+        }
+        self.last_price_update_ts = timestamp;
+    }
+
+    //  deserialize accounts of any layout, fields missing from legacy accounts are zeroed
+    pub fn try_deserialize_padded(data: &[u8]) -> Result<Self> {
+        let mut padded = data.to_vec();
+        if padded.len() < 8 + Self::INIT_SPACE {
+            padded.resize(8 + Self::INIT_SPACE, 0);
+        }
+        Self::try_deserialize(&mut padded.as_slice())
+    }
+
+    //  buys are restricted to the allowlist until `presale_end_ts`
+    pub fn is_presale_active(&self, current_timestamp: i64) -> bool {
+        self.presale_merkle_root != [0u8; 32] && current_timestamp < self.presale_end_ts
+    }
+}
+
+//  time weighted average price between two observations of `price_cumulative`
+//  returns None if the observations are not in order
+pub fn twap(
+    start_cumulative: u128,
+    start_ts: i64,
+    end_cumulative: u128,
+    end_ts: i64,
+) -> Option<u64> {
+    let elapsed = end_ts.checked_sub(start_ts).filter(|elapsed| *elapsed > 0)? as u128;
+    let average = end_cumulative.wrapping_sub(start_cumulative) / elapsed;
+    u64::try_from(average).ok()
+}
+
+//  one OHLC bucket, prices use PRICE_SCALE and volume is in lamports
+#[zero_copy]
+#[derive(Default, Debug, PartialEq)]
+pub struct Candle {
+    pub open_ts: i64,
+    pub open: u64,
+    pub high: u64,
+    pub low: u64,
+    pub close: u64,
+    pub volume: u64,
+}
+
+//  ring buffer of the most recent candles of a curve, updated on every swap
+#[account(zero_copy)]
+pub struct CurveCandles {
+    pub bonding_curve: Pubkey,
+    pub interval: i64,
+    pub head: u64, // index of the most recent candle
+    pub count: u64,
+    pub candles: [Candle; CANDLE_COUNT],
+}
+
+impl CurveCandles {
+    pub fn record(&mut self, timestamp: i64, price_before: u64, price_after: u64, volume: u64) {
+        let open_ts = timestamp - timestamp.rem_euclid(self.interval);
+
+        //  start a new bucket, opening at the last known price
+        if self.count == 0 || self.candles[self.head as usize].open_ts != open_ts {
+            if self.count > 0 {
+                self.head = (self.head + 1) % CANDLE_COUNT as u64;
+            }
+            self.count = (self.count + 1).min(CANDLE_COUNT as u64);
+            self.candles[self.head as usize] = Candle {
+                open_ts,
+                open: price_before,
+                high: price_before,
+                low: price_before,
+                close: price_before,
+                volume: 0,
+            };
+        }
+
+        let candle = &mut self.candles[self.head as usize];
+        candle.high = candle.high.max(price_after);
+        candle.low = candle.low.min(price_after);
+        candle.close = price_after;
+        candle.volume = candle.volume.saturating_add(volume);
+    }
+}
+
+//  optional presale settings passed to `launch`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct PresaleConfig {
+    pub merkle_root: [u8; 32],
+    pub end_ts: i64, // at most MAX_PRESALE_DURATION after the launch
+    pub wallet_cap: u64,
+}
+
+//  launch history of a creator, created by their first launch
+#[account]
+#[derive(InitSpace)]
+pub struct CreatorRegistry {
+    pub creator: Pubkey,
+    pub launches: u64,
+    //  counted by swaps once the registry exists
+    pub graduated: u64,    // curves that completed
+    pub total_volume: u64, // lamports traded on the creator's curves, fees included
+    pub last_mint: Pubkey,
+    pub last_launch_ts: i64,
+
+    //  ring of the latest launch timestamps, the next one goes to `launches % RECENT_LAUNCH_COUNT`
+    pub recent_launch_ts: [i64; RECENT_LAUNCH_COUNT],
+}
+
+impl CreatorRegistry {
+    //  rejects the launch if the creator already used up the window, records it otherwise
+    pub fn record_launch(&mut self, limit: &LaunchRateLimit, timestamp: i64) -> Result<()> {
+        if limit.max_launches > 0 {
+            let recent = self
+                .recent_launch_ts
+                .iter()
+                .filter(|launch_ts| **launch_ts != 0 && timestamp - **launch_ts < limit.window)
+                .count();
+            if recent >= limit.max_launches as usize {
+                msg!(
+                    "{} launches in the last {} seconds, at most {} allowed",
+                    recent,
+                    limit.window,
+                    limit.max_launches
+                );
+                return err!(PumpfunError::LaunchRateLimited);
+            }
+        }
+
+        self.recent_launch_ts[(self.launches % RECENT_LAUNCH_COUNT as u64) as usize] = timestamp;
+        self.launches = self
+            .launches
+            .checked_add(1)
+            .ok_or(PumpfunError::OverflowOrUnderflowOccurred)?;
+        self.last_launch_ts = timestamp;
+
+        Ok(())
+    }
+}
+
+//  holds the team allocation of a curve until it vests
+#[account]
+#[derive(InitSpace)]
+pub struct VestingEscrow {
+    pub bonding_curve: Pubkey,
+    pub mint: Pubkey,
+    pub total_amount: u64,
+    pub claimed_amount: u64,
+    pub start_ts: i64,
+    pub schedule: VestingSchedule, // copied from the config at launch
+    pub bump: u8,
+}
+
+impl VestingEscrow {
+    pub fn vested_amount(&self, timestamp: i64) -> u64 {
+        let elapsed = timestamp.saturating_sub(self.start_ts);
+        if elapsed < self.schedule.cliff {
+            return 0;
+        }
+        if elapsed >= self.schedule.duration {
+            return self.total_amount;
+        }
+
+        (self.total_amount as u128 * elapsed as u128 / self.schedule.duration as u128) as u64
+    }
+}
+
+//  tracks how much an allowlisted wallet has bought during the presale
+#[account]
+#[derive(InitSpace)]
+pub struct PresaleReceipt {
+    pub bonding_curve: Pubkey,
+    pub buyer: Pubkey,
+    pub purchased_lamports: u64,
 }
 pub trait BondingCurveAccount<'info> {
     // Updates the token reserves in the liquidity pool
@@ -98,13 +1107,9 @@ pub trait BondingCurveAccount<'info> {
         &mut self,
         global_config: &Account<'info, Config>,
         token_mint: &Account<'info, Mint>,
-        global_ata: &mut AccountInfo<'info>,
+        vault_ata: &mut AccountInfo<'info>,
         user_ata: &mut AccountInfo<'info>,
         source: &mut AccountInfo<'info>,
-        team_wallet: &mut AccountInfo<'info>,
-        _team_wallet_ata: &mut AccountInfo<'info>,
-        dev_wallet: Option<&mut AccountInfo<'info>>,
-        _dev_wallet_ata: Option<&mut AccountInfo<'info>>,
         amount: u64,
         direction: u8,
         minimum_receive_amount: u64,
@@ -113,9 +1118,10 @@ pub trait BondingCurveAccount<'info> {
         signer: &[&[&[u8]]],
         token_program: &Program<'info, Token>,
         system_program: &Program<'info, System>,
-    ) -> Result<u64>;
+    ) -> Result<SwapEvent>;
 
     // Calculate the output amount and the fee amounts (in SOL) for a swap
+    #[allow(clippy::too_many_arguments)]
     fn cal_amount_out(
         &self,
         amount: u64, // Input amount (tokens if selling, SOL if buying)
@@ -135,6 +1141,8 @@ impl<'info> BondingCurveAccount<'info> for Account<'info, BondingCurve> {
         reserve_token: u64,
         reserve_lamport: u64,
     ) -> Result<bool> {
+        self.accumulate_price(Clock::get()?.unix_timestamp);
+
         self.reserve_token = reserve_token;
         self.reserve_lamport = reserve_lamport;
 
@@ -152,14 +1160,10 @@ impl<'info> BondingCurveAccount<'info> for Account<'info, BondingCurve> {
         global_config: &Account<'info, Config>,
 
         token_mint: &Account<'info, Mint>,
-        global_ata: &mut AccountInfo<'info>,
+        vault_ata: &mut AccountInfo<'info>,
         user_ata: &mut AccountInfo<'info>,
 
         source: &mut AccountInfo<'info>,
-        team_wallet: &mut AccountInfo<'info>,
-        _team_wallet_ata: &mut AccountInfo<'info>,
-        dev_wallet: Option<&mut AccountInfo<'info>>,
-        _dev_wallet_ata: Option<&mut AccountInfo<'info>>,
 
         amount: u64,
         direction: u8,
@@ -171,7 +1175,7 @@ impl<'info> BondingCurveAccount<'info> for Account<'info, BondingCurve> {
 
         token_program: &Program<'info, Token>,
         system_program: &Program<'info, System>,
-    ) -> Result<u64> {
+    ) -> Result<SwapEvent> {
         if amount == 0 {
             return err!(PumpfunError::InvalidAmount);
         }
@@ -260,49 +1264,23 @@ impl<'info> BondingCurveAccount<'info> for Account<'info, BondingCurve> {
 
             self.update_reserves(global_config, new_reserve_token, new_reserve_lamport)?;
 
-            msg! {"Reserves: {:?} {:?}", new_reserve_token, new_reserve_lamport};
-
             // Transfer tokens from user to pool
             token_transfer_user(
                 user_ata.clone(),
                 user,
-                global_ata.clone(),
+                vault_ata.clone(),
                 token_program,
                 amount_to_swap, // Transfer the full input token amount
             )?;
 
             // Transfer NET SOL from pool to user
             sol_transfer_with_signer(
-                source.clone(), // global_vault
+                source.clone(), // curve_vault
                 user.to_account_info(),
                 system_program,
                 signer,
                 amount_out, // Transfer net SOL amount
             )?;
-
-            // Transfer platform fee to team wallet
-            if platform_fee > 0 {
-                sol_transfer_with_signer(
-                    source.clone(), // global_vault
-                    team_wallet.clone(),
-                    system_program,
-                    signer,
-                    platform_fee,
-                )?;
-            }
-
-            // Transfer dev fee if enabled
-            if dev_fee > 0 && global_config.dev_fee_enabled {
-                if let Some(dev_wallet_info) = dev_wallet {
-                    sol_transfer_with_signer(
-                        source.clone(), // global_vault
-                        dev_wallet_info.clone(),
-                        system_program,
-                        signer,
-                        dev_fee,
-                    )?;
-                }
-            }
         } else {
             // Buying Tokens with SOL
             // amount_to_swap = input SOL used in calculation (potentially capped)
@@ -323,22 +1301,11 @@ impl<'info> BondingCurveAccount<'info> for Account<'info, BondingCurve> {
                 .checked_add(adjusted_sol_input) // Add SOL used for swap (amount_to_swap - fee)
                 .ok_or(PumpfunError::OverflowOrUnderflowOccurred)?;
 
-            let is_completed =
-                self.update_reserves(global_config, new_reserve_token, new_reserve_lamport)?;
-
-            if is_completed {
-                emit!(CompleteEvent {
-                    user: user.key(),
-                    mint: token_mint.key(),
-                    bonding_curve: self.key()
-                });
-            }
-
-            msg! {"Reserves: {:?} {:?}", new_reserve_token, new_reserve_lamport};
+            self.update_reserves(global_config, new_reserve_token, new_reserve_lamport)?;
 
             // Transfer tokens from pool to user
             token_transfer_with_signer(
-                global_ata.clone(),
+                vault_ata.clone(),
                 source.clone(),
                 user_ata.clone(),
                 token_program,
@@ -349,38 +1316,31 @@ impl<'info> BondingCurveAccount<'info> for Account<'info, BondingCurve> {
             // Transfer SOL from user to pool
             // User sends the full amount_to_swap (SOL potentially capped by curve limit)
             sol_transfer_from_user(user, source.clone(), system_program, amount_to_swap)?;
-
-            // Transfer platform fee to team wallet
-            if platform_fee > 0 {
-                sol_transfer_with_signer(
-                    source.clone(), // global_vault
-                    team_wallet.clone(),
-                    system_program,
-                    signer,
-                    platform_fee,
-                )?;
-            }
-
-            // Transfer dev fee if enabled
-            if dev_fee > 0 && global_config.dev_fee_enabled {
-                if let Some(dev_wallet_info) = dev_wallet {
-                    sol_transfer_with_signer(
-                        source.clone(), // global_vault
-                        dev_wallet_info.clone(),
-                        system_program,
-                        signer,
-                        dev_fee,
-                    )?;
-                }
-            }
         }
-        msg!(
-            "SwapEvent: {:?} {:?} {:?}",
-            user.key(),
+
+        //  fees stay in the curve vault until `sweep_fees`, so swaps only lock curve accounts
+        self.accrued_platform_fee = self
+            .accrued_platform_fee
+            .checked_add(platform_fee)
+            .ok_or(PumpfunError::OverflowOrUnderflowOccurred)?;
+        self.accrued_dev_fee = self
+            .accrued_dev_fee
+            .checked_add(dev_fee)
+            .ok_or(PumpfunError::OverflowOrUnderflowOccurred)?;
+
+        Ok(SwapEvent {
+            user: user.key(),
+            mint: token_mint.key(),
+            bonding_curve: self.key(),
             direction,
-            amount_out
-        );
-        Ok(amount_out)
+            amount_in: amount_to_swap,
+            amount_out,
+            platform_fee,
+            dev_fee,
+            reserve_token: self.reserve_token,
+            reserve_lamport: self.reserve_lamport,
+            timestamp: current_timestamp,
+        })
     }
 
     // Calculate the output amount and the fee amounts (in SOL) for a swap
@@ -496,3 +1456,503 @@ impl<'info> BondingCurveAccount<'info> for Account<'info, BondingCurve> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::Discriminator;
+
+    fn error_code<T: Debug>(result: Result<T>) -> u32 {
+        match result.unwrap_err() {
+            Error::AnchorError(error) => error.error_code_number,
+            error => panic!("unexpected error {error:?}"),
+        }
+    }
+
+    #[test]
+    fn amount_step_rejects_values_off_the_step() {
+        let config = AmountConfig::Step { min: 10u64, max: 100, step: 5 };
+        assert!(config.validate(&10).is_ok());
+        assert!(config.validate(&25).is_ok());
+        assert!(config.validate(&100).is_ok());
+        assert_eq!(error_code(config.validate(&27)), u32::from(ValueNotOnStep));
+        assert_eq!(error_code(config.validate(&5)), u32::from(ValueTooSmall));
+        assert_eq!(error_code(config.validate(&105)), u32::from(ValueTooLarge));
+    }
+
+    #[test]
+    fn amount_check_rejects_min_above_max() {
+        let range = AmountConfig::Range { min: Some(10u64), max: Some(5) };
+        assert_eq!(error_code(range.check()), u32::from(InvalidAmountRange));
+
+        let step = AmountConfig::Step { min: 10u64, max: 5, step: 1 };
+        assert_eq!(error_code(step.check()), u32::from(InvalidAmountRange));
+
+        let open_range = AmountConfig::Range { min: Some(10u64), max: None };
+        assert!(open_range.check().is_ok());
+    }
+
+    #[test]
+    fn amount_check_rejects_zero_step() {
+        let step = AmountConfig::Step { min: 0u64, max: 10, step: 0 };
+        assert_eq!(error_code(step.check()), u32::from(InvalidAmountStep));
+    }
+
+    #[test]
+    fn amount_check_rejects_empty_configs() {
+        assert_eq!(error_code(AmountConfig::<u64>::All(vec![]).check()), u32::from(EmptyAmountConfig));
+        assert_eq!(error_code(AmountConfig::<u64>::Enum(vec![]).check()), u32::from(EmptyAmountConfig));
+    }
+
+    #[test]
+    fn amount_all_checks_every_rule() {
+        let config = AmountConfig::All(vec![
+            AmountRule::Range { min: Some(1u64), max: None },
+            AmountRule::Step { min: 0, max: 10, step: 0 },
+        ]);
+        assert_eq!(error_code(config.check()), u32::from(InvalidAmountStep));
+    }
+
+    #[test]
+    fn amount_all_max_is_the_lowest_rule_max() {
+        let config = AmountConfig::All(vec![
+            AmountRule::Range { min: None, max: Some(50u64) },
+            AmountRule::Step { min: 0, max: 100, step: 10 },
+            AmountRule::Enum(vec![30, 70]),
+        ]);
+        assert_eq!(config.max_value(), Some(&50));
+
+        let unbounded = AmountConfig::All(vec![AmountRule::Range { min: Some(1u64), max: None }]);
+        assert_eq!(unbounded.max_value(), None);
+    }
+
+    #[test]
+    fn amount_all_value_has_to_satisfy_every_rule() {
+        let config = AmountConfig::All(vec![
+            AmountRule::Range { min: None, max: Some(50u64) },
+            AmountRule::Step { min: 0, max: 100, step: 10 },
+            AmountRule::Enum(vec![30, 40, 70]),
+        ]);
+        assert!(config.validate(&30).is_ok());
+        assert_eq!(error_code(config.validate(&70)), u32::from(ValueTooLarge));
+        assert_eq!(error_code(config.validate(&35)), u32::from(ValueNotOnStep));
+        assert_eq!(error_code(config.validate(&20)), u32::from(ValueInvalid));
+    }
+
+    #[test]
+    fn config_rejects_platform_fees_above_100_percent() {
+        let mut config = legacy_config().migrate();
+        config.platform_buy_fee = 10_001;
+        assert_eq!(error_code(config.validate()), u32::from(PlatformFeeTooHigh));
+
+        let mut config = legacy_config().migrate();
+        config.platform_sell_fee = 10_001;
+        assert_eq!(error_code(config.validate()), u32::from(PlatformFeeTooHigh));
+    }
+
+    #[test]
+    fn config_rejects_fee_split_above_100_percent() {
+        let mut config = legacy_config().migrate();
+        config.trading_fee_bps = 5_001;
+        config.dev_fee_share_bps = 5_000;
+        assert_eq!(error_code(config.validate()), u32::from(FeeSplitTooHigh));
+
+        config.trading_fee_bps = 5_000;
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn config_rejects_init_bonding_curve_out_of_range() {
+        for init_bonding_curve in [100.5, -0.5, f64::NAN] {
+            let mut config = legacy_config().migrate();
+            config.init_bonding_curve = init_bonding_curve;
+            assert_eq!(error_code(config.validate()), u32::from(InvalidInitBondingCurve));
+        }
+    }
+
+    #[test]
+    fn config_rejects_curve_limit_not_above_max_lamports() {
+        let mut config = legacy_config().migrate();
+        config.lamport_amount_config = AmountConfig::Range { min: Some(1), max: Some(config.curve_limit) };
+        assert_eq!(error_code(config.validate()), u32::from(CurveLimitTooLow));
+
+        //  unbounded lamports can always reach the limit
+        config.lamport_amount_config = AmountConfig::Range { min: Some(1), max: None };
+        assert_eq!(error_code(config.validate()), u32::from(CurveLimitTooLow));
+
+        config.lamport_amount_config = AmountConfig::Range { min: Some(1), max: Some(config.curve_limit - 1) };
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn config_rejects_empty_amount_enum() {
+        let mut config = legacy_config().migrate();
+        config.token_supply_config = AmountConfig::Enum(vec![]);
+        assert_eq!(error_code(config.validate()), u32::from(EmptyAmountConfig));
+    }
+
+    fn metadata_policy() -> MetadataPolicy {
+        MetadataPolicy {
+            max_name_len: 32,
+            max_symbol_len: 10,
+            max_uri_len: 64,
+            allowed_uri_prefixes: vec!["https://".into(), "ipfs://".into()],
+            symbol_charset: Some("ABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789".into()),
+            on_graduation: GraduationMetadata::Keep,
+        }
+    }
+
+    #[test]
+    fn metadata_policy_accepts_allowed_metadata() {
+        let policy = metadata_policy();
+        assert!(policy.validate().is_ok());
+        assert!(policy.check("Ape", "APE", "https://example.com/ape.json").is_ok());
+        assert!(policy.check("Ape", "APE2", "ipfs://bafybeigdyrzt").is_ok());
+    }
+
+    #[test]
+    fn metadata_policy_rejects_script_and_data_uris() {
+        let policy = metadata_policy();
+        assert_eq!(
+            error_code(policy.check("Ape", "APE", "javascript:alert(1)")),
+            u32::from(UriSchemeNotAllowed)
+        );
+        assert_eq!(
+            error_code(policy.check("Ape", "APE", "data:text/html;base64,PHNjcmlwdD4=")),
+            u32::from(UriSchemeNotAllowed)
+        );
+    }
+
+    #[test]
+    fn metadata_policy_enforces_length_limits() {
+        let policy = metadata_policy();
+        let uri = format!("https://{}", "a".repeat(56));
+        assert!(policy.check(&"a".repeat(32), &"A".repeat(10), &uri).is_ok());
+
+        assert_eq!(error_code(policy.check(&"a".repeat(33), "APE", &uri)), u32::from(NameTooLong));
+        assert_eq!(error_code(policy.check("Ape", &"A".repeat(11), &uri)), u32::from(SymbolTooLong));
+        assert_eq!(error_code(policy.check("Ape", "APE", &format!("{}a", uri))), u32::from(UriTooLong));
+    }
+
+    #[test]
+    fn metadata_policy_enforces_the_symbol_charset() {
+        let mut policy = metadata_policy();
+        assert_eq!(
+            error_code(policy.check("Ape", "APE$", "https://example.com")),
+            u32::from(InvalidSymbolCharacter)
+        );
+
+        policy.symbol_charset = None;
+        assert!(policy.check("Ape", "APE$", "https://example.com").is_ok());
+    }
+
+    #[test]
+    fn metadata_policy_validate_rejects_empty_prefixes() {
+        let mut policy = metadata_policy();
+        policy.allowed_uri_prefixes.push(String::new());
+        assert_eq!(error_code(policy.validate()), u32::from(InvalidMetadataPolicy));
+
+        policy.allowed_uri_prefixes.clear();
+        assert_eq!(error_code(policy.validate()), u32::from(InvalidMetadataPolicy));
+    }
+
+    fn allocation(bps: u16) -> LaunchAllocation {
+        LaunchAllocation { recipient: Pubkey::new_unique(), bps }
+    }
+
+    #[test]
+    fn allocation_limits_cap_the_count() {
+        let limits = AllocationLimits { max_allocations: 2, max_allocation_bps: 500, min_curve_bps: 0 };
+        assert_eq!(limits.check(&[]).unwrap(), 0);
+        assert_eq!(limits.check(&[allocation(100), allocation(200)]).unwrap(), 300);
+        assert_eq!(
+            error_code(limits.check(&[allocation(100), allocation(100), allocation(100)])),
+            u32::from(TooManyAllocations)
+        );
+    }
+
+    #[test]
+    fn allocation_limits_reject_empty_and_oversized_shares() {
+        let limits = AllocationLimits { max_allocations: 2, max_allocation_bps: 500, min_curve_bps: 0 };
+        assert!(limits.check(&[allocation(500)]).is_ok());
+        assert_eq!(error_code(limits.check(&[allocation(501)])), u32::from(AllocationTooLarge));
+        assert_eq!(error_code(limits.check(&[allocation(0)])), u32::from(AllocationTooLarge));
+    }
+
+    #[test]
+    fn allocation_limits_reject_duplicate_recipients() {
+        let limits = AllocationLimits { max_allocations: 2, max_allocation_bps: 500, min_curve_bps: 0 };
+        let first = allocation(100);
+        assert_eq!(error_code(limits.check(&[first, first])), u32::from(DuplicateAccount));
+    }
+
+    #[test]
+    fn allocation_limits_validate_against_the_allocation_cap() {
+        let mut limits = AllocationLimits {
+            max_allocations: MAX_LAUNCH_ALLOCATIONS as u8,
+            max_allocation_bps: 10_000,
+            min_curve_bps: 10_000,
+        };
+        assert!(limits.validate().is_ok());
+
+        limits.max_allocations += 1;
+        assert_eq!(error_code(limits.validate()), u32::from(InvalidAllocationLimits));
+    }
+
+    fn empty_candles() -> Box<CurveCandles> {
+        let mut candles: Box<CurveCandles> = Box::new(bytemuck::Zeroable::zeroed());
+        candles.interval = 60;
+        candles
+    }
+
+    #[test]
+    fn candle_swaps_in_one_interval_share_a_bucket() {
+        let mut candles = empty_candles();
+        candles.record(120, 100, 150, 10);
+        candles.record(179, 150, 90, 5);
+
+        assert_eq!(candles.count, 1);
+        assert_eq!(
+            candles.candles[0],
+            Candle { open_ts: 120, open: 100, high: 150, low: 90, close: 90, volume: 15 }
+        );
+    }
+
+    #[test]
+    fn candle_new_interval_opens_at_previous_close() {
+        let mut candles = empty_candles();
+        candles.record(120, 100, 150, 10);
+        candles.record(185, 150, 160, 1);
+
+        assert_eq!(candles.count, 2);
+        assert_eq!(candles.head, 1);
+        assert_eq!(
+            candles.candles[1],
+            Candle { open_ts: 180, open: 150, high: 160, low: 150, close: 160, volume: 1 }
+        );
+    }
+
+    #[test]
+    fn candle_ring_wraps_after_candle_count() {
+        let mut candles = empty_candles();
+        for i in 0..=CANDLE_COUNT as i64 {
+            candles.record(i * 60, 1, 1, 1);
+        }
+
+        assert_eq!(candles.count, CANDLE_COUNT as u64);
+        assert_eq!(candles.head, 0);
+        assert_eq!(candles.candles[0].open_ts, CANDLE_COUNT as i64 * 60);
+        assert_eq!(candles.candles[1].open_ts, 60);
+    }
+
+    fn legacy_config() -> LegacyConfig {
+        LegacyConfig {
+            authority: Pubkey::new_unique(),
+            pending_authority: Pubkey::default(),
+            team_wallet: Pubkey::new_unique(),
+            dev_wallet: Pubkey::new_unique(),
+            init_bonding_curve: 80.0,
+            platform_buy_fee: 0,
+            platform_sell_fee: 0,
+            trading_fee_bps: 100,
+            dev_fee_share_bps: 5000,
+            dev_fee_enabled: true,
+            curve_limit: 85_000_000_000,
+            lamport_amount_config: AmountConfig::Range { min: Some(1_000_000), max: Some(1_000_000_000) },
+            token_supply_config: AmountConfig::Range { min: Some(1_000_000), max: Some(1_000_000_000) },
+            token_decimals_config: AmountConfig::Range { min: Some(6), max: Some(9) },
+        }
+    }
+
+    #[test]
+    fn legacy_config_does_not_decode_as_config() {
+        let data = [&Config::DISCRIMINATOR, legacy_config().try_to_vec().unwrap().as_slice()].concat();
+        assert!(Config::try_deserialize(&mut data.as_slice()).is_err());
+    }
+
+    #[test]
+    fn migrated_config_keeps_legacy_fields_and_validates() {
+        let legacy = legacy_config();
+        let config = legacy.clone().migrate();
+
+        assert!(config.validate().is_ok());
+        assert_eq!(config.authority, legacy.authority);
+        assert_eq!(config.curve_limit, legacy.curve_limit);
+        assert_eq!(config.lamport_amount_config, legacy.lamport_amount_config);
+
+        let data = [&Config::DISCRIMINATOR, config.try_to_vec().unwrap().as_slice()].concat();
+        assert_eq!(Config::try_deserialize(&mut data.as_slice()).unwrap(), config);
+    }
+
+    fn discount(creator: Pubkey, discount_bps: u16) -> LaunchFeeDiscount {
+        LaunchFeeDiscount { creator, discount_bps }
+    }
+
+    #[test]
+    fn launch_fee_discount_change_never_adds_an_entry() {
+        let creator = Pubkey::new_unique();
+        let mut config = legacy_config().migrate();
+        config.launch_fee_discounts = vec![discount(creator, 5000)];
+
+        config.set_launch_fee_discount(&discount(creator, 2000));
+        assert_eq!(config.launch_fee_discounts, vec![discount(creator, 2000)]);
+
+        //  removed after the change was queued
+        config.set_launch_fee_discount(&discount(creator, 0));
+        config.set_launch_fee_discount(&discount(creator, 1000));
+        assert!(config.launch_fee_discounts.is_empty());
+    }
+
+    #[test]
+    fn launch_fee_discounts_can_only_be_kept_or_raised() {
+        let (first, second) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mut config = legacy_config().migrate();
+        config.launch_fee_discounts = vec![discount(first, 5000), discount(second, 1000)];
+
+        assert!(config.keeps_launch_fee_discounts(&[discount(second, 1000), discount(first, 6000)]));
+        assert!(!config.keeps_launch_fee_discounts(&[discount(first, 4000), discount(second, 1000)]));
+        assert!(!config.keeps_launch_fee_discounts(&[discount(first, 5000)]));
+        assert!(!config.keeps_launch_fee_discounts(&[]));
+    }
+
+    fn empty_registry() -> CreatorRegistry {
+        CreatorRegistry {
+            creator: Pubkey::new_unique(),
+            launches: 0,
+            graduated: 0,
+            total_volume: 0,
+            last_mint: Pubkey::default(),
+            last_launch_ts: 0,
+            recent_launch_ts: [0; RECENT_LAUNCH_COUNT],
+        }
+    }
+
+    #[test]
+    fn record_launch_rejects_launches_over_the_limit() {
+        let limit = LaunchRateLimit { max_launches: 3, window: 100 };
+        let mut registry = empty_registry();
+        for timestamp in [1_000, 1_010, 1_020] {
+            assert!(registry.record_launch(&limit, timestamp).is_ok());
+        }
+
+        assert_eq!(
+            error_code(registry.record_launch(&limit, 1_099)),
+            u32::from(LaunchRateLimited)
+        );
+        assert_eq!(registry.launches, 3);
+        assert_eq!(registry.last_launch_ts, 1_020);
+    }
+
+    #[test]
+    fn record_launch_frees_slots_as_the_window_rolls() {
+        let limit = LaunchRateLimit { max_launches: 3, window: 100 };
+        let mut registry = empty_registry();
+        for timestamp in [1_000, 1_010, 1_020] {
+            assert!(registry.record_launch(&limit, timestamp).is_ok());
+        }
+
+        //  the launch at 1_000 is exactly one window old
+        assert!(registry.record_launch(&limit, 1_100).is_ok());
+        assert_eq!(
+            error_code(registry.record_launch(&limit, 1_109)),
+            u32::from(LaunchRateLimited)
+        );
+        assert!(registry.record_launch(&limit, 1_110).is_ok());
+        assert_eq!(registry.launches, 5);
+    }
+
+    #[test]
+    fn record_launch_wraps_the_timestamp_ring() {
+        let limit = LaunchRateLimit { max_launches: RECENT_LAUNCH_COUNT as u8, window: 100 };
+        let mut registry = empty_registry();
+        for i in 0..RECENT_LAUNCH_COUNT as i64 {
+            assert!(registry.record_launch(&limit, 1_000 + i).is_ok());
+        }
+        assert_eq!(
+            error_code(registry.record_launch(&limit, 1_050)),
+            u32::from(LaunchRateLimited)
+        );
+
+        assert!(registry.record_launch(&limit, 1_100).is_ok());
+        assert_eq!(registry.recent_launch_ts[0], 1_100);
+    }
+
+    #[test]
+    fn record_launch_without_limit_always_records() {
+        let limit = LaunchRateLimit { max_launches: 0, window: 0 };
+        let mut registry = empty_registry();
+        for _ in 0..RECENT_LAUNCH_COUNT * 2 {
+            assert!(registry.record_launch(&limit, 1_000).is_ok());
+        }
+        assert_eq!(registry.launches, RECENT_LAUNCH_COUNT as u64 * 2);
+    }
+
+    fn escrow(cliff: i64, duration: i64) -> VestingEscrow {
+        VestingEscrow {
+            bonding_curve: Pubkey::new_unique(),
+            mint: Pubkey::new_unique(),
+            total_amount: 1_000,
+            claimed_amount: 0,
+            start_ts: 10_000,
+            schedule: VestingSchedule { cliff, duration },
+            bump: 255,
+        }
+    }
+
+    #[test]
+    fn vesting_nothing_before_the_cliff() {
+        let escrow = escrow(100, 1_000);
+        assert_eq!(escrow.vested_amount(9_000), 0);
+        assert_eq!(escrow.vested_amount(10_099), 0);
+    }
+
+    #[test]
+    fn vesting_at_the_cliff_unlocks_the_linear_share() {
+        let escrow = escrow(100, 1_000);
+        assert_eq!(escrow.vested_amount(10_100), 100);
+        assert_eq!(escrow.vested_amount(10_500), 500);
+    }
+
+    #[test]
+    fn vesting_cliff_after_duration_unlocks_everything_at_the_cliff() {
+        let escrow = escrow(2_000, 1_000);
+        assert_eq!(escrow.vested_amount(11_999), 0);
+        assert_eq!(escrow.vested_amount(12_000), 1_000);
+    }
+
+    #[test]
+    fn vesting_zero_duration_unlocks_everything_at_the_cliff() {
+        assert_eq!(escrow(0, 0).vested_amount(10_000), 1_000);
+
+        let escrow = escrow(100, 0);
+        assert_eq!(escrow.vested_amount(10_099), 0);
+        assert_eq!(escrow.vested_amount(10_100), 1_000);
+    }
+
+    #[test]
+    fn vesting_past_duration_is_capped_at_total() {
+        let escrow = escrow(100, 1_000);
+        assert_eq!(escrow.vested_amount(11_000), 1_000);
+        assert_eq!(escrow.vested_amount(i64::MAX), 1_000);
+    }
+
+    #[test]
+    fn twap_averages_over_elapsed_time() {
+        assert_eq!(twap(1_000, 100, 4_000, 130), Some(100));
+    }
+
+    #[test]
+    fn twap_rejects_zero_elapsed() {
+        assert_eq!(twap(1_000, 100, 1_000, 100), None);
+    }
+
+    #[test]
+    fn twap_rejects_reversed_observations() {
+        assert_eq!(twap(4_000, 130, 1_000, 100), None);
+    }
+
+    #[test]
+    fn twap_handles_wrapped_accumulator() {
+        assert_eq!(twap(u128::MAX - 99, 0, 200, 10), Some(30));
+    }
+}
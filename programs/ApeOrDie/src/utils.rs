@@ -1,6 +1,6 @@
 use crate::*;
 use anchor_spl::token::{self, Token};
-use anchor_lang::solana_program;
+use anchor_lang::solana_program::{self, keccak};
 
 // TODO, remove all floats and conversions for precision
 pub fn convert_to_float(value: u64, decimals: u8) -> f64 {
@@ -104,3 +104,146 @@ pub fn token_transfer_with_signer<'info>(
 
     Ok(())
 }
+
+//  verify a merkle proof of `leaf` against `root`, pairs are hashed in sorted order
+pub fn verify_merkle_proof(proof: &[[u8; 32]], root: &[u8; 32], leaf: [u8; 32]) -> bool {
+    let computed = proof.iter().fold(leaf, |node, sibling| {
+        if node <= *sibling {
+            keccak::hashv(&[&node, sibling]).to_bytes()
+        } else {
+            keccak::hashv(&[sibling, &node]).to_bytes()
+        }
+    });
+    computed == *root
+}
+
+//  presale allowlist leaf for a buyer
+pub fn presale_leaf(buyer: &Pubkey) -> [u8; 32] {
+    keccak::hash(buyer.as_ref()).to_bytes()
+}
+
+//  create an account owned by `owner`, also works when the address was pre-funded
+pub fn create_account_with_signer<'info>(
+    account: AccountInfo<'info>,
+    space: usize,
+    owner: &Pubkey,
+    payer: &Signer<'info>,
+    system_program: &Program<'info, System>,
+    signers_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let rent_cost = Rent::get()?.minimum_balance(space);
+
+    if account.lamports() == 0 {
+        return anchor_lang::system_program::create_account(
+            CpiContext::new_with_signer(
+                system_program.to_account_info(),
+                anchor_lang::system_program::CreateAccount {
+                    from: payer.to_account_info(),
+                    to: account,
+                },
+                signers_seeds,
+            ),
+            rent_cost,
+            space as u64,
+            owner,
+        );
+    }
+
+    let lamport_delta = rent_cost.saturating_sub(account.lamports());
+    if lamport_delta > 0 {
+        sol_transfer_from_user(payer, account.clone(), system_program, lamport_delta)?;
+    }
+    anchor_lang::system_program::allocate(
+        CpiContext::new_with_signer(
+            system_program.to_account_info(),
+            anchor_lang::system_program::Allocate {
+                account_to_allocate: account.clone(),
+            },
+            signers_seeds,
+        ),
+        space as u64,
+    )?;
+    anchor_lang::system_program::assign(
+        CpiContext::new_with_signer(
+            system_program.to_account_info(),
+            anchor_lang::system_program::Assign {
+                account_to_assign: account,
+            },
+            signers_seeds,
+        ),
+        owner,
+    )
+}
+
+//  grow a program account to `new_len`, payer tops up the rent
+pub fn realloc_with_rent<'info>(
+    account: AccountInfo<'info>,
+    new_len: usize,
+    payer: &Signer<'info>,
+    system_program: &Program<'info, System>,
+) -> Result<()> {
+    if new_len <= account.data_len() {
+        return Ok(());
+    }
+
+    let rent_cost = Rent::get()?.minimum_balance(new_len);
+    let lamport_delta = rent_cost.saturating_sub(account.lamports());
+    if lamport_delta > 0 {
+        sol_transfer_from_user(payer, account.clone(), system_program, lamport_delta)?;
+    }
+    account.realloc(new_len, false)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    //  allowlist of wallets [1; 32]..=[4; 32], built off-chain with sorted pair hashing
+    const ROOT: &str = "3df2c0c59f5153b5751383c7ac514416ec1b3085b96fe67b461a3c1893a22b78";
+    const LEAF_2: &str = "ee4a079f5b14a24465181d45af32a8053c2d446446d7019359e210b82e53b8ba";
+    const NODE_34: &str = "fea25366faacc54cd55736725f8c84688cb6f523c6356aa79d172f0aa8673ff6";
+
+    fn from_hex(hex: &str) -> [u8; 32] {
+        let mut bytes = [0u8; 32];
+        for (i, byte) in bytes.iter_mut().enumerate() {
+            *byte = u8::from_str_radix(&hex[2 * i..2 * i + 2], 16).unwrap();
+        }
+        bytes
+    }
+
+    fn wallet(i: u8) -> Pubkey {
+        Pubkey::new_from_array([i; 32])
+    }
+
+    #[test]
+    fn presale_leaf_matches_known_value() {
+        assert_eq!(presale_leaf(&wallet(2)), from_hex(LEAF_2));
+    }
+
+    #[test]
+    fn merkle_proof_accepts_allowlisted_wallet() {
+        let proof = [from_hex(LEAF_2), from_hex(NODE_34)];
+        assert!(verify_merkle_proof(&proof, &from_hex(ROOT), presale_leaf(&wallet(1))));
+    }
+
+    #[test]
+    fn merkle_proof_rejects_other_wallet() {
+        let proof = [from_hex(LEAF_2), from_hex(NODE_34)];
+        assert!(!verify_merkle_proof(&proof, &from_hex(ROOT), presale_leaf(&wallet(5))));
+    }
+
+    #[test]
+    fn merkle_proof_rejects_truncated_proof() {
+        let proof = [from_hex(LEAF_2)];
+        assert!(!verify_merkle_proof(&proof, &from_hex(ROOT), presale_leaf(&wallet(1))));
+    }
+
+    #[test]
+    fn empty_proof_matches_single_leaf_root() {
+        let leaf = presale_leaf(&wallet(1));
+        assert!(verify_merkle_proof(&[], &leaf, leaf));
+        assert!(!verify_merkle_proof(&[], &from_hex(ROOT), leaf));
+    }
+}
@@ -57,10 +57,16 @@ console.log("ENV: ", process.env.ENV);
     deployer.wallet.publicKey.toBase58()
   );
 
+  const noRole = {
+    holder: web3.PublicKey.default,
+    pending: web3.PublicKey.default,
+    nominatedAt: new BN(0),
+  };
+
   // Define the configuration data
   const newConfig = {
     authority: deployer.wallet.publicKey,
-    pendingAuthority: deployer.wallet.publicKey,
+    pendingAuthority: web3.PublicKey.default,
     teamWallet: deployer.wallet.publicKey,
     devWallet: deployer.wallet.publicKey,
     initBondingCurve: 100,
@@ -69,7 +75,7 @@ console.log("ENV: ", process.env.ENV);
     tradingFeeBps: 100, // 1%
     devFeeShareBps: 5000, // 50%
     devFeeEnabled: true,
-    curveLimit: new BN(100),
+    curveLimit: new BN(85000000000), // 85 SOL, must exceed the max virtual lamports
     lamportAmountConfig: {
       range: {
         min: new BN(1000000), // 1 SOL
@@ -88,6 +94,45 @@ console.log("ENV: ", process.env.ENV);
         max: 9,
       },
     },
+    configChangeDelay: new BN(2 * 24 * 60 * 60), // fee & wallet changes wait 2 days
+    // roles are handed out afterwards through nominateRole / acceptRole
+    feeAdmin: noRole,
+    pauseGuardian: noRole,
+    migrationOperator: noRole,
+    launchModerator: noRole,
+    paused: false,
+    pendingAuthorityNominatedAt: new BN(0),
+    nominationValidity: new BN(3 * 24 * 60 * 60), // nominations expire after 3 days
+    curveLimitConfig: {
+      range: {
+        min: new BN(10000000000), // 10 SOL
+        max: new BN(500000000000), // 500 SOL
+      },
+    },
+    metadataPolicy: {
+      maxNameLen: 32,
+      maxSymbolLen: 10,
+      maxUriLen: 200,
+      allowedUriPrefixes: ["https://", "ipfs://", "ar://"],
+      symbolCharset: null, // any characters, the sample launch uses "$Turkey"
+      onGraduation: { transferToCreator: {} },
+    },
+    launchFeeLamports: new BN(20000000), // 0.02 SOL, deters spam launches
+    launchFeeDiscounts: [],
+    launchRateLimit: {
+      maxLaunches: 3, // per creator per day
+      window: new BN(24 * 60 * 60),
+    },
+    teamVesting: {
+      cliff: new BN(30 * 24 * 60 * 60), // nothing unlocks for 30 days
+      duration: new BN(365 * 24 * 60 * 60), // fully vested after a year
+    },
+    allocationLimits: {
+      maxAllocations: 4, // e.g. creator, community treasury and agent
+      maxAllocationBps: 500, // 5% of the supply per recipient
+      minCurveBps: 6000, // the curve keeps at least 60%
+    },
+    tierId: 0, // standard launches, other tiers are created by this authority
   };
 
   // Add logging to verify the configuration object
@@ -99,6 +144,7 @@ console.log("ENV: ", process.env.ENV);
   const accounts = {
     payer: deployer.wallet.publicKey,
     config: configPDA,
+    rootConfig: null, // only needed to create another tier
     globalVault: globalVaultPDA,
     globalWsolAccount: globalWsolAccountPDA,
     nativeMint: nativeMint,
@@ -70,6 +70,18 @@ const TOKEN_METADATA_PROGRAM_ID = new PublicKey(
     program.programId
   );
 
+  // Derive the PDA for the candle account which stores price history
+  const [curveCandlesPDA] = web3.PublicKey.findProgramAddressSync(
+    [Buffer.from("candles"), bondingCurvePDA.toBuffer()],
+    program.programId
+  );
+
+  // Derive the PDA for the creator registry which stores launch history
+  const [creatorRegistryPDA] = web3.PublicKey.findProgramAddressSync(
+    [Buffer.from("creator_registry"), provider.wallet.publicKey.toBuffer()],
+    program.programId
+  );
+
   // Derive the PDA for the token metadata account
   const [tokenMetadataPDA] = web3.PublicKey.findProgramAddressSync(
     [
@@ -80,20 +92,32 @@ const TOKEN_METADATA_PROGRAM_ID = new PublicKey(
     TOKEN_METADATA_PROGRAM_ID
   );
 
-  // Derive the PDA for the global token account
-  const [globalTokenAccountPDA] = web3.PublicKey.findProgramAddressSync(
+  // Derive the PDA for the curve vault which holds the SOL of this curve
+  const [curveVaultPDA] = web3.PublicKey.findProgramAddressSync(
+    [Buffer.from("curve_vault"), bondingCurvePDA.toBuffer()],
+    program.programId
+  );
+
+  // Derive the PDA for the curve vault token account
+  const [curveVaultATAPDA] = web3.PublicKey.findProgramAddressSync(
     [
-      globalVaultPDA.toBuffer(),
+      curveVaultPDA.toBuffer(),
       TOKEN_PROGRAM_ID.toBuffer(),
       tokenMintKp.publicKey.toBuffer(),
     ],
     ASSOCIATED_TOKEN_PROGRAM_ID
   );
 
-  // Derive the PDA for the team wallet associated token account (ATA)
-  const [teamWalletATAPDA] = web3.PublicKey.findProgramAddressSync(
+  // Derive the PDA for the vesting escrow which holds the team allocation
+  const [vestingEscrowPDA] = web3.PublicKey.findProgramAddressSync(
+    [Buffer.from("vesting_escrow"), bondingCurvePDA.toBuffer()],
+    program.programId
+  );
+
+  // Derive the PDA for the vesting escrow associated token account (ATA)
+  const [vestingEscrowATAPDA] = web3.PublicKey.findProgramAddressSync(
     [
-      configAccount.teamWallet.toBuffer(),
+      vestingEscrowPDA.toBuffer(),
       TOKEN_PROGRAM_ID.toBuffer(),
       tokenMintKp.publicKey.toBuffer(),
     ],
@@ -104,7 +128,7 @@ const TOKEN_METADATA_PROGRAM_ID = new PublicKey(
   try {
     //  here is our program calling the launch methods
     const tx = await program.methods
-      .launch(decimals, tokenSupply, reserveLamport, name, symbol, uri)
+      .launch(decimals, tokenSupply, reserveLamport, name, symbol, uri, null, null, null, [])
       .accounts({
         // @ts-ignore
         globalConfig: configPDA,
@@ -112,15 +136,19 @@ const TOKEN_METADATA_PROGRAM_ID = new PublicKey(
         creator: provider.wallet.publicKey,
         token: tokenMintKp.publicKey,
         bondingCurve: bondingCurvePDA,
+        curveCandles: curveCandlesPDA,
+        creatorRegistry: creatorRegistryPDA,
         tokenMetadataAccount: tokenMetadataPDA,
-        globalTokenAccount: globalTokenAccountPDA,
+        curveVault: curveVaultPDA,
+        curveVaultAta: curveVaultATAPDA,
         systemProgram: SystemProgram.programId,
         rent: SYSVAR_RENT_PUBKEY,
         tokenProgram: TOKEN_PROGRAM_ID,
         associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
         mplTokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
         teamWallet: configAccount.teamWallet,
-        teamWalletAta: teamWalletATAPDA,
+        vestingEscrow: vestingEscrowPDA,
+        vestingEscrowAta: vestingEscrowATAPDA,
       })
       .signers([signerWallet, tokenMintKp])
       .rpc();
@@ -0,0 +1,33 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { ApeOrDie } from "../target/types/ape_or_die";
+import * as web3 from "@solana/web3.js";
+
+// Rewrites the deployed config in the current layout, run once after upgrading the program
+(async () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+  const program = anchor.workspace.ApeOrDie as Program<ApeOrDie>;
+
+  const [configPDA] = web3.PublicKey.findProgramAddressSync(
+    [Buffer.from("config")],
+    program.programId
+  );
+
+  try {
+    const txSignature = await program.methods
+      .migrateConfig()
+      .accounts({
+        payer: provider.wallet.publicKey,
+        // @ts-ignore
+        config: configPDA,
+        systemProgram: web3.SystemProgram.programId,
+      })
+      .rpc();
+
+    console.log("Config migrated:", txSignature);
+    console.log("Config:", await program.account.config.fetch(configPDA));
+  } catch (err) {
+    console.error("Config migration failed:", err);
+  }
+})();
@@ -33,271 +33,206 @@ export type ApeOrDie = {
         },
         {
           "name": "globalConfig",
-          "writable": true,
+          "writable": true
+        },
+        {
+          "name": "eventAuthority",
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  99,
-                  111,
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
                   110,
-                  102,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
                   105,
-                  103
+                  116,
+                  121
                 ]
               }
             ]
           }
+        },
+        {
+          "name": "program"
         }
       ],
       "args": []
     },
     {
-      "name": "configure",
+      "name": "acceptRole",
       "discriminator": [
-        245,
+        52,
+        25,
         7,
-        108,
-        117,
-        95,
+        231,
         196,
-        54,
-        217
+        235,
+        119,
+        253
       ],
       "accounts": [
         {
-          "name": "payer",
+          "name": "newHolder",
           "writable": true,
           "signer": true
         },
         {
-          "name": "config",
-          "writable": true,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  99,
-                  111,
-                  110,
-                  102,
-                  105,
-                  103
-                ]
-              }
-            ]
-          }
-        },
-        {
-          "name": "globalVault",
-          "writable": true,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  103,
-                  108,
-                  111,
-                  98,
-                  97,
-                  108
-                ]
-              }
-            ]
-          }
+          "name": "globalConfig",
+          "writable": true
         },
         {
-          "name": "globalWsolAccount",
-          "writable": true,
+          "name": "eventAuthority",
           "pda": {
             "seeds": [
-              {
-                "kind": "account",
-                "path": "globalVault"
-              },
               {
                 "kind": "const",
                 "value": [
-                  6,
-                  221,
-                  246,
-                  225,
-                  215,
+                  95,
+                  95,
                   101,
-                  161,
-                  147,
-                  217,
-                  203,
-                  225,
-                  70,
-                  206,
-                  235,
-                  121,
-                  172,
-                  28,
-                  180,
-                  133,
-                  237,
+                  118,
+                  101,
+                  110,
+                  116,
                   95,
-                  91,
-                  55,
-                  145,
-                  58,
-                  140,
-                  245,
-                  133,
-                  126,
-                  255,
-                  0,
-                  169
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
                 ]
-              },
-              {
-                "kind": "account",
-                "path": "nativeMint"
               }
-            ],
-            "program": {
-              "kind": "const",
-              "value": [
-                140,
-                151,
-                37,
-                143,
-                78,
-                36,
-                137,
-                241,
-                187,
-                61,
-                16,
-                41,
-                20,
-                142,
-                13,
-                131,
-                11,
-                90,
-                19,
-                153,
-                218,
-                255,
-                16,
-                132,
-                4,
-                142,
-                123,
-                216,
-                219,
-                233,
-                248,
-                89
-              ]
-            }
+            ]
           }
         },
         {
-          "name": "nativeMint",
-          "address": "So11111111111111111111111111111111111111112"
-        },
-        {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
-        },
-        {
-          "name": "tokenProgram",
-          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
-        },
-        {
-          "name": "associatedTokenProgram",
-          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+          "name": "program"
         }
       ],
       "args": [
         {
-          "name": "newConfig",
+          "name": "role",
           "type": {
             "defined": {
-              "name": "config"
+              "name": "role"
             }
           }
         }
       ]
     },
     {
-      "name": "launch",
+      "name": "audit",
       "discriminator": [
-        153,
-        241,
-        93,
-        225,
-        22,
-        69,
-        74,
-        61
+        115,
+        235,
+        38,
+        1,
+        115,
+        58,
+        218,
+        81
       ],
       "accounts": [
         {
-          "name": "globalConfig",
-          "writable": true,
+          "name": "globalVault",
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  99,
+                  103,
+                  108,
                   111,
-                  110,
-                  102,
-                  105,
-                  103
+                  98,
+                  97,
+                  108
                 ]
               }
             ]
           }
         },
         {
-          "name": "globalVault",
-          "writable": true,
+          "name": "eventAuthority",
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  103,
-                  108,
-                  111,
-                  98,
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
                   97,
-                  108
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
                 ]
               }
             ]
           }
         },
         {
-          "name": "creator",
+          "name": "program"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "cancelConfigChange",
+      "discriminator": [
+        222,
+        114,
+        136,
+        167,
+        183,
+        86,
+        61,
+        158
+      ],
+      "accounts": [
+        {
+          "name": "admin",
           "writable": true,
           "signer": true
         },
         {
-          "name": "token",
-          "writable": true,
-          "signer": true
+          "name": "globalConfig"
         },
         {
-          "name": "bondingCurve",
+          "name": "pendingConfigChange",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  98,
-                  111,
+                  112,
+                  101,
                   110,
                   100,
                   105,
@@ -305,126 +240,222 @@ export type ApeOrDie = {
                   103,
                   95,
                   99,
-                  117,
-                  114,
-                  118,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103,
+                  95,
+                  99,
+                  104,
+                  97,
+                  110,
+                  103,
                   101
                 ]
               },
               {
                 "kind": "account",
-                "path": "token"
+                "path": "globalConfig"
               }
             ]
           }
         },
         {
-          "name": "tokenMetadataAccount",
-          "writable": true,
+          "name": "eventAuthority",
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  109,
+                  95,
+                  95,
+                  101,
+                  118,
                   101,
+                  110,
                   116,
+                  95,
                   97,
-                  100,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "cancelNomination",
+      "discriminator": [
+        95,
+        2,
+        136,
+        89,
+        181,
+        232,
+        153,
+        174
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "globalConfig",
+          "writable": true
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
                   97,
+                  117,
                   116,
-                  97
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "role",
+          "type": {
+            "option": {
+              "defined": {
+                "name": "role"
+              }
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "claimVested",
+      "discriminator": [
+        208,
+        190,
+        166,
+        114,
+        203,
+        225,
+        140,
+        208
+      ],
+      "accounts": [
+        {
+          "name": "globalConfig"
+        },
+        {
+          "name": "teamWallet",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "tokenMint"
+        },
+        {
+          "name": "bondingCurve",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  111,
+                  110,
+                  100,
+                  105,
+                  110,
+                  103,
+                  95,
+                  99,
+                  117,
+                  114,
+                  118,
+                  101
                 ]
               },
+              {
+                "kind": "account",
+                "path": "tokenMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vestingEscrow",
+          "writable": true,
+          "pda": {
+            "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  11,
-                  112,
+                  118,
                   101,
-                  177,
-                  227,
-                  209,
-                  124,
-                  69,
-                  56,
-                  157,
-                  82,
-                  127,
-                  107,
-                  4,
-                  195,
-                  205,
-                  88,
-                  184,
-                  108,
                   115,
-                  26,
-                  160,
-                  253,
-                  181,
-                  73,
-                  182,
-                  209,
-                  188,
-                  3,
-                  248,
-                  41,
-                  70
+                  116,
+                  105,
+                  110,
+                  103,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
                 ]
               },
               {
                 "kind": "account",
-                "path": "token"
+                "path": "bondingCurve"
               }
-            ],
-            "program": {
-              "kind": "const",
-              "value": [
-                11,
-                112,
-                101,
-                177,
-                227,
-                209,
-                124,
-                69,
-                56,
-                157,
-                82,
-                127,
-                107,
-                4,
-                195,
-                205,
-                88,
-                184,
-                108,
-                115,
-                26,
-                160,
-                253,
-                181,
-                73,
-                182,
-                209,
-                188,
-                3,
-                248,
-                41,
-                70
-              ]
-            }
+            ]
           }
         },
         {
-          "name": "globalTokenAccount",
+          "name": "vestingEscrowAta",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "account",
-                "path": "globalVault"
+                "path": "vestingEscrow"
               },
               {
                 "kind": "const",
@@ -465,7 +496,7 @@ export type ApeOrDie = {
               },
               {
                 "kind": "account",
-                "path": "token"
+                "path": "tokenMint"
               }
             ],
             "program": {
@@ -507,30 +538,6 @@ export type ApeOrDie = {
             }
           }
         },
-        {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
-        },
-        {
-          "name": "rent",
-          "address": "SysvarRent111111111111111111111111111111111"
-        },
-        {
-          "name": "tokenProgram",
-          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
-        },
-        {
-          "name": "associatedTokenProgram",
-          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
-        },
-        {
-          "name": "mplTokenMetadataProgram",
-          "address": "metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s"
-        },
-        {
-          "name": "teamWallet",
-          "writable": true
-        },
         {
           "name": "teamWalletAta",
           "writable": true,
@@ -579,7 +586,7 @@ export type ApeOrDie = {
               },
               {
                 "kind": "account",
-                "path": "token"
+                "path": "tokenMint"
               }
             ],
             "program": {
@@ -620,56 +627,79 @@ export type ApeOrDie = {
               ]
             }
           }
-        }
-      ],
-      "args": [
-        {
-          "name": "decimals",
-          "type": "u8"
         },
         {
-          "name": "tokenSupply",
-          "type": "u64"
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
         },
         {
-          "name": "virtualLamportReserves",
-          "type": "u64"
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
         },
         {
-          "name": "name",
-          "type": "string"
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
         },
         {
-          "name": "symbol",
-          "type": "string"
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
         },
         {
-          "name": "uri",
-          "type": "string"
+          "name": "program"
         }
-      ]
+      ],
+      "args": []
     },
     {
-      "name": "nominateAuthority",
+      "name": "configure",
       "discriminator": [
-        148,
-        182,
-        144,
-        91,
-        186,
-        12,
-        118,
-        18
+        245,
+        7,
+        108,
+        117,
+        95,
+        196,
+        54,
+        217
       ],
       "accounts": [
         {
-          "name": "admin",
+          "name": "payer",
           "writable": true,
           "signer": true
         },
         {
-          "name": "globalConfig",
-          "writable": true,
+          "name": "config",
+          "writable": true
+        },
+        {
+          "name": "rootConfig",
+          "optional": true,
           "pda": {
             "seeds": [
               {
@@ -685,58 +715,34 @@ export type ApeOrDie = {
               }
             ]
           }
-        }
-      ],
-      "args": [
-        {
-          "name": "newAdmin",
-          "type": "pubkey"
-        }
-      ]
-    },
-    {
-      "name": "swap",
-      "discriminator": [
-        248,
-        198,
-        158,
-        145,
-        225,
-        117,
-        135,
-        200
-      ],
-      "accounts": [
+        },
         {
-          "name": "globalConfig",
+          "name": "globalVault",
+          "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  99,
+                  103,
+                  108,
                   111,
-                  110,
-                  102,
-                  105,
-                  103
+                  98,
+                  97,
+                  108
                 ]
               }
             ]
           }
         },
         {
-          "name": "teamWallet",
-          "writable": true
-        },
-        {
-          "name": "teamWalletAta",
+          "name": "globalWsolAccount",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "account",
-                "path": "teamWallet"
+                "path": "globalVault"
               },
               {
                 "kind": "const",
@@ -777,7 +783,7 @@ export type ApeOrDie = {
               },
               {
                 "kind": "account",
-                "path": "tokenMint"
+                "path": "nativeMint"
               }
             ],
             "program": {
@@ -820,111 +826,97 @@ export type ApeOrDie = {
           }
         },
         {
-          "name": "devWallet",
-          "writable": true,
-          "optional": true
+          "name": "nativeMint",
+          "address": "So11111111111111111111111111111111111111112"
         },
         {
-          "name": "devWalletAta",
-          "writable": true,
-          "optional": true,
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "eventAuthority",
           "pda": {
             "seeds": [
-              {
-                "kind": "account",
-                "path": "devWallet"
-              },
               {
                 "kind": "const",
                 "value": [
-                  6,
-                  221,
-                  246,
-                  225,
-                  215,
+                  95,
+                  95,
                   101,
-                  161,
-                  147,
-                  217,
-                  203,
-                  225,
-                  70,
-                  206,
-                  235,
-                  121,
-                  172,
-                  28,
-                  180,
-                  133,
-                  237,
+                  118,
+                  101,
+                  110,
+                  116,
                   95,
-                  91,
-                  55,
-                  145,
-                  58,
-                  140,
-                  245,
-                  133,
-                  126,
-                  255,
-                  0,
-                  169
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
                 ]
-              },
-              {
-                "kind": "account",
-                "path": "tokenMint"
               }
-            ],
-            "program": {
-              "kind": "const",
-              "value": [
-                140,
-                151,
-                37,
-                143,
-                78,
-                36,
-                137,
-                241,
-                187,
-                61,
-                16,
-                41,
-                20,
-                142,
-                13,
-                131,
-                11,
-                90,
-                19,
-                153,
-                218,
-                255,
-                16,
-                132,
-                4,
-                142,
-                123,
-                216,
-                219,
-                233,
-                248,
-                89
-              ]
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "newConfig",
+          "type": {
+            "defined": {
+              "name": "config"
             }
           }
+        }
+      ]
+    },
+    {
+      "name": "executeConfigChange",
+      "discriminator": [
+        100,
+        150,
+        198,
+        236,
+        103,
+        241,
+        118,
+        71
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true
         },
         {
-          "name": "bondingCurve",
+          "name": "globalConfig",
+          "writable": true
+        },
+        {
+          "name": "pendingConfigChange",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  98,
-                  111,
+                  112,
+                  101,
                   110,
                   100,
                   105,
@@ -932,294 +924,254 @@ export type ApeOrDie = {
                   103,
                   95,
                   99,
-                  117,
-                  114,
-                  118,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103,
+                  95,
+                  99,
+                  104,
+                  97,
+                  110,
+                  103,
                   101
                 ]
               },
               {
                 "kind": "account",
-                "path": "tokenMint"
+                "path": "globalConfig"
               }
             ]
           }
         },
         {
-          "name": "globalVault",
-          "writable": true,
+          "name": "eventAuthority",
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  103,
-                  108,
-                  111,
-                  98,
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
                   97,
-                  108
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
                 ]
               }
             ]
           }
         },
+        {
+          "name": "program"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initCandles",
+      "discriminator": [
+        119,
+        123,
+        98,
+        1,
+        199,
+        194,
+        238,
+        202
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
         {
           "name": "tokenMint"
         },
         {
-          "name": "globalAta",
+          "name": "bondingCurve",
           "writable": true,
           "pda": {
             "seeds": [
-              {
-                "kind": "account",
-                "path": "globalVault"
-              },
               {
                 "kind": "const",
                 "value": [
-                  6,
-                  221,
-                  246,
-                  225,
-                  215,
-                  101,
-                  161,
-                  147,
-                  217,
-                  203,
-                  225,
-                  70,
-                  206,
-                  235,
-                  121,
-                  172,
-                  28,
-                  180,
-                  133,
-                  237,
+                  98,
+                  111,
+                  110,
+                  100,
+                  105,
+                  110,
+                  103,
                   95,
-                  91,
-                  55,
-                  145,
-                  58,
-                  140,
-                  245,
-                  133,
-                  126,
-                  255,
-                  0,
-                  169
+                  99,
+                  117,
+                  114,
+                  118,
+                  101
                 ]
               },
               {
                 "kind": "account",
                 "path": "tokenMint"
               }
-            ],
-            "program": {
-              "kind": "const",
-              "value": [
-                140,
-                151,
-                37,
-                143,
-                78,
-                36,
-                137,
-                241,
-                187,
-                61,
-                16,
-                41,
-                20,
-                142,
-                13,
-                131,
-                11,
-                90,
-                19,
-                153,
-                218,
-                255,
-                16,
-                132,
-                4,
-                142,
-                123,
-                216,
-                219,
-                233,
-                248,
-                89
-              ]
-            }
+            ]
           }
         },
         {
-          "name": "userAta",
+          "name": "curveCandles",
           "writable": true,
           "pda": {
             "seeds": [
-              {
-                "kind": "account",
-                "path": "user"
-              },
               {
                 "kind": "const",
                 "value": [
-                  6,
-                  221,
-                  246,
-                  225,
-                  215,
+                  99,
+                  97,
+                  110,
+                  100,
+                  108,
                   101,
-                  161,
-                  147,
-                  217,
-                  203,
-                  225,
-                  70,
-                  206,
-                  235,
-                  121,
-                  172,
-                  28,
-                  180,
-                  133,
-                  237,
-                  95,
-                  91,
-                  55,
-                  145,
-                  58,
-                  140,
-                  245,
-                  133,
-                  126,
-                  255,
-                  0,
-                  169
+                  115
                 ]
               },
               {
                 "kind": "account",
-                "path": "tokenMint"
+                "path": "bondingCurve"
               }
-            ],
-            "program": {
-              "kind": "const",
-              "value": [
-                140,
-                151,
-                37,
-                143,
-                78,
-                36,
-                137,
-                241,
-                187,
-                61,
-                16,
-                41,
-                20,
-                142,
-                13,
-                131,
-                11,
-                90,
-                19,
-                153,
-                218,
-                255,
-                16,
-                132,
-                4,
-                142,
-                123,
-                216,
-                219,
-                233,
-                248,
-                89
-              ]
-            }
+            ]
           }
         },
-        {
-          "name": "user",
-          "writable": true,
-          "signer": true
-        },
         {
           "name": "systemProgram",
           "address": "11111111111111111111111111111111"
-        },
-        {
-          "name": "tokenProgram",
-          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
-        },
-        {
-          "name": "associatedTokenProgram",
-          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
-        }
-      ],
-      "args": [
-        {
-          "name": "amount",
-          "type": "u64"
-        },
-        {
-          "name": "direction",
-          "type": "u8"
-        },
-        {
-          "name": "minimumReceiveAmount",
-          "type": "u64"
-        },
-        {
-          "name": "deadline",
-          "type": "i64"
         }
       ],
-      "returns": "u64"
+      "args": []
     },
     {
-      "name": "withdraw",
+      "name": "initCreatorRegistry",
       "discriminator": [
+        14,
+        71,
+        229,
         183,
-        18,
-        70,
-        156,
+        169,
         148,
-        109,
-        161,
-        34
+        232,
+        211
       ],
       "accounts": [
         {
-          "name": "globalConfig",
+          "name": "payer",
           "writable": true,
+          "signer": true
+        },
+        {
+          "name": "tokenMint"
+        },
+        {
+          "name": "bondingCurve",
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  99,
+                  98,
                   111,
                   110,
-                  102,
+                  100,
                   105,
-                  103
+                  110,
+                  103,
+                  95,
+                  99,
+                  117,
+                  114,
+                  118,
+                  101
                 ]
+              },
+              {
+                "kind": "account",
+                "path": "tokenMint"
               }
             ]
           }
         },
         {
-          "name": "globalVault",
+          "name": "creatorRegistry",
           "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  114,
+                  101,
+                  97,
+                  116,
+                  111,
+                  114,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "bonding_curve.creator",
+                "account": "bondingCurve"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "launch",
+      "discriminator": [
+        153,
+        241,
+        93,
+        225,
+        22,
+        69,
+        74,
+        61
+      ],
+      "accounts": [
+        {
+          "name": "globalConfig",
+          "writable": true
+        },
+        {
+          "name": "globalVault",
           "pda": {
             "seeds": [
               {
@@ -1237,12 +1189,13 @@ export type ApeOrDie = {
           }
         },
         {
-          "name": "admin",
+          "name": "creator",
           "writable": true,
           "signer": true
         },
         {
-          "name": "tokenMint"
+          "name": "token",
+          "writable": true
         },
         {
           "name": "bondingCurve",
@@ -1269,19 +1222,203 @@ export type ApeOrDie = {
               },
               {
                 "kind": "account",
-                "path": "tokenMint"
+                "path": "token"
               }
             ]
           }
         },
         {
-          "name": "globalVaultAta",
+          "name": "curveCandles",
           "writable": true,
           "pda": {
             "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  97,
+                  110,
+                  100,
+                  108,
+                  101,
+                  115
+                ]
+              },
               {
                 "kind": "account",
-                "path": "globalVault"
+                "path": "bondingCurve"
+              }
+            ]
+          }
+        },
+        {
+          "name": "creatorRegistry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  114,
+                  101,
+                  97,
+                  116,
+                  111,
+                  114,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "creator"
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenMetadataAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  101,
+                  116,
+                  97,
+                  100,
+                  97,
+                  116,
+                  97
+                ]
+              },
+              {
+                "kind": "const",
+                "value": [
+                  11,
+                  112,
+                  101,
+                  177,
+                  227,
+                  209,
+                  124,
+                  69,
+                  56,
+                  157,
+                  82,
+                  127,
+                  107,
+                  4,
+                  195,
+                  205,
+                  88,
+                  184,
+                  108,
+                  115,
+                  26,
+                  160,
+                  253,
+                  181,
+                  73,
+                  182,
+                  209,
+                  188,
+                  3,
+                  248,
+                  41,
+                  70
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "token"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                11,
+                112,
+                101,
+                177,
+                227,
+                209,
+                124,
+                69,
+                56,
+                157,
+                82,
+                127,
+                107,
+                4,
+                195,
+                205,
+                88,
+                184,
+                108,
+                115,
+                26,
+                160,
+                253,
+                181,
+                73,
+                182,
+                209,
+                188,
+                3,
+                248,
+                41,
+                70
+              ]
+            }
+          }
+        },
+        {
+          "name": "curveVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  117,
+                  114,
+                  118,
+                  101,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "bondingCurve"
+              }
+            ]
+          }
+        },
+        {
+          "name": "curveVaultAta",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "curveVault"
               },
               {
                 "kind": "const",
@@ -1322,7 +1459,7 @@ export type ApeOrDie = {
               },
               {
                 "kind": "account",
-                "path": "tokenMint"
+                "path": "token"
               }
             ],
             "program": {
@@ -1365,13 +1502,68 @@ export type ApeOrDie = {
           }
         },
         {
-          "name": "adminAta",
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "rent",
+          "address": "SysvarRent111111111111111111111111111111111"
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "mplTokenMetadataProgram",
+          "address": "metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s"
+        },
+        {
+          "name": "teamWallet",
+          "writable": true
+        },
+        {
+          "name": "vestingEscrow",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  101,
+                  115,
+                  116,
+                  105,
+                  110,
+                  103,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "bondingCurve"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vestingEscrowAta",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "account",
-                "path": "admin"
+                "path": "vestingEscrow"
               },
               {
                 "kind": "const",
@@ -1412,7 +1604,7 @@ export type ApeOrDie = {
               },
               {
                 "kind": "account",
-                "path": "tokenMint"
+                "path": "token"
               }
             ],
             "program": {
@@ -1455,206 +1647,4929 @@ export type ApeOrDie = {
           }
         },
         {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
         },
         {
-          "name": "tokenProgram",
-          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "decimals",
+          "type": "u8"
         },
         {
-          "name": "associatedTokenProgram",
-          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+          "name": "tokenSupply",
+          "type": "u64"
+        },
+        {
+          "name": "virtualLamportReserves",
+          "type": "u64"
+        },
+        {
+          "name": "name",
+          "type": "string"
+        },
+        {
+          "name": "symbol",
+          "type": "string"
+        },
+        {
+          "name": "uri",
+          "type": "string"
+        },
+        {
+          "name": "presale",
+          "type": {
+            "option": {
+              "defined": {
+                "name": "presaleConfig"
+              }
+            }
+          }
+        },
+        {
+          "name": "curveLimit",
+          "type": {
+            "option": "u64"
+          }
+        },
+        {
+          "name": "mintNonce",
+          "type": {
+            "option": "u64"
+          }
+        },
+        {
+          "name": "allocations",
+          "type": {
+            "vec": {
+              "defined": {
+                "name": "launchAllocation"
+              }
+            }
+          }
         }
-      ],
-      "args": []
-    }
-  ],
-  "accounts": [
-    {
-      "name": "bondingCurve",
-      "discriminator": [
-        23,
-        183,
-        248,
-        55,
-        96,
-        216,
-        172,
-        96
       ]
     },
     {
-      "name": "config",
+      "name": "migrateConfig",
       "discriminator": [
-        155,
-        12,
-        170,
+        92,
+        131,
+        58,
+        105,
+        210,
+        154,
         224,
-        30,
-        250,
-        204,
-        130
-      ]
-    }
-  ],
-  "events": [
-    {
-      "name": "completeEvent",
-      "discriminator": [
-        95,
-        114,
-        97,
-        156,
-        212,
-        46,
-        152,
-        8
-      ]
-    }
-  ],
-  "errors": [
-    {
-      "code": 6000,
-      "name": "valueTooSmall",
-      "msg": "valueTooSmall"
-    },
-    {
-      "code": 6001,
-      "name": "valueTooLarge",
-      "msg": "valueTooLarge"
-    },
-    {
-      "code": 6002,
-      "name": "valueInvalid",
-      "msg": "valueInvalid"
-    },
-    {
-      "code": 6003,
-      "name": "incorrectConfigAccount",
-      "msg": "incorrectConfigAccount"
+        193
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": []
     },
     {
-      "code": 6004,
-      "name": "incorrectAuthority",
-      "msg": "incorrectAuthority"
+      "name": "migrateCurveVault",
+      "discriminator": [
+        136,
+        245,
+        98,
+        196,
+        188,
+        127,
+        166,
+        127
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "globalVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  108,
+                  111,
+                  98,
+                  97,
+                  108
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenMint"
+        },
+        {
+          "name": "bondingCurve",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  111,
+                  110,
+                  100,
+                  105,
+                  110,
+                  103,
+                  95,
+                  99,
+                  117,
+                  114,
+                  118,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "tokenMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "curveVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  117,
+                  114,
+                  118,
+                  101,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "bondingCurve"
+              }
+            ]
+          }
+        },
+        {
+          "name": "globalVaultAta",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "globalVault"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "tokenMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "curveVaultAta",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "curveVault"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "tokenMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "nominateAuthority",
+      "discriminator": [
+        148,
+        182,
+        144,
+        91,
+        186,
+        12,
+        118,
+        18
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "globalConfig",
+          "writable": true
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "newAdmin",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "nominateRole",
+      "discriminator": [
+        212,
+        195,
+        75,
+        137,
+        172,
+        212,
+        236,
+        74
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "globalConfig",
+          "writable": true
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "role",
+          "type": {
+            "defined": {
+              "name": "role"
+            }
+          }
+        },
+        {
+          "name": "newHolder",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "queueConfigChange",
+      "discriminator": [
+        170,
+        159,
+        206,
+        172,
+        182,
+        240,
+        205,
+        53
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "globalConfig"
+        },
+        {
+          "name": "pendingConfigChange",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  101,
+                  110,
+                  100,
+                  105,
+                  110,
+                  103,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103,
+                  95,
+                  99,
+                  104,
+                  97,
+                  110,
+                  103,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "globalConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "change",
+          "type": {
+            "defined": {
+              "name": "configChange"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "renounceAuthority",
+      "discriminator": [
+        78,
+        110,
+        117,
+        127,
+        89,
+        23,
+        253,
+        153
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "globalConfig",
+          "writable": true
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "revokeRole",
+      "discriminator": [
+        179,
+        232,
+        2,
+        180,
+        48,
+        227,
+        82,
+        7
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "globalConfig",
+          "writable": true
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "role",
+          "type": {
+            "defined": {
+              "name": "role"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "setCurveParams",
+      "discriminator": [
+        133,
+        183,
+        61,
+        96,
+        198,
+        233,
+        62,
+        13
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "globalConfig",
+          "writable": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "params",
+          "type": {
+            "defined": {
+              "name": "curveParams"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "setLaunchFeeDiscounts",
+      "discriminator": [
+        115,
+        81,
+        251,
+        38,
+        111,
+        162,
+        16,
+        70
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "globalConfig",
+          "writable": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "discounts",
+          "type": {
+            "vec": {
+              "defined": {
+                "name": "launchFeeDiscount"
+              }
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "setLaunchLimits",
+      "discriminator": [
+        112,
+        192,
+        200,
+        0,
+        47,
+        165,
+        35,
+        201
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "globalConfig",
+          "writable": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "params",
+          "type": {
+            "defined": {
+              "name": "launchLimits"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "setMetadataPolicy",
+      "discriminator": [
+        112,
+        22,
+        239,
+        153,
+        136,
+        242,
+        25,
+        205
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "globalConfig",
+          "writable": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "policy",
+          "type": {
+            "defined": {
+              "name": "metadataPolicy"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "setPaused",
+      "discriminator": [
+        91,
+        60,
+        125,
+        192,
+        176,
+        225,
+        166,
+        218
+      ],
+      "accounts": [
+        {
+          "name": "guardian",
+          "signer": true
+        },
+        {
+          "name": "globalConfig",
+          "writable": true
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "paused",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "swap",
+      "discriminator": [
+        248,
+        198,
+        158,
+        145,
+        225,
+        117,
+        135,
+        200
+      ],
+      "accounts": [
+        {
+          "name": "globalConfig"
+        },
+        {
+          "name": "bondingCurve",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  111,
+                  110,
+                  100,
+                  105,
+                  110,
+                  103,
+                  95,
+                  99,
+                  117,
+                  114,
+                  118,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "tokenMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "curveCandles",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  97,
+                  110,
+                  100,
+                  108,
+                  101,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "bondingCurve"
+              }
+            ]
+          }
+        },
+        {
+          "name": "creatorRegistry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  114,
+                  101,
+                  97,
+                  116,
+                  111,
+                  114,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "bonding_curve.creator",
+                "account": "bondingCurve"
+              }
+            ]
+          }
+        },
+        {
+          "name": "curveVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  117,
+                  114,
+                  118,
+                  101,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "bondingCurve"
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenMint"
+        },
+        {
+          "name": "curveVaultAta",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "curveVault"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "tokenMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "userAta",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "user"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "tokenMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "user",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "presaleReceipt",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  101,
+                  115,
+                  97,
+                  108,
+                  101,
+                  95,
+                  114,
+                  101,
+                  99,
+                  101,
+                  105,
+                  112,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "bondingCurve"
+              },
+              {
+                "kind": "account",
+                "path": "user"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        },
+        {
+          "name": "direction",
+          "type": "u8"
+        },
+        {
+          "name": "minimumReceiveAmount",
+          "type": "u64"
+        },
+        {
+          "name": "deadline",
+          "type": "i64"
+        },
+        {
+          "name": "presaleProof",
+          "type": {
+            "vec": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        }
+      ],
+      "returns": "u64"
+    },
+    {
+      "name": "sweepFees",
+      "discriminator": [
+        175,
+        225,
+        98,
+        71,
+        118,
+        66,
+        34,
+        148
+      ],
+      "accounts": [
+        {
+          "name": "globalConfig"
+        },
+        {
+          "name": "teamWallet",
+          "writable": true
+        },
+        {
+          "name": "devWallet",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "tokenMint"
+        },
+        {
+          "name": "bondingCurve",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  111,
+                  110,
+                  100,
+                  105,
+                  110,
+                  103,
+                  95,
+                  99,
+                  117,
+                  114,
+                  118,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "tokenMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "curveVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  117,
+                  114,
+                  118,
+                  101,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "bondingCurve"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "updateMetadata",
+      "discriminator": [
+        170,
+        182,
+        43,
+        239,
+        97,
+        78,
+        225,
+        186
+      ],
+      "accounts": [
+        {
+          "name": "globalConfig"
+        },
+        {
+          "name": "globalVault",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  108,
+                  111,
+                  98,
+                  97,
+                  108
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "creator",
+          "signer": true
+        },
+        {
+          "name": "tokenMint"
+        },
+        {
+          "name": "bondingCurve",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  111,
+                  110,
+                  100,
+                  105,
+                  110,
+                  103,
+                  95,
+                  99,
+                  117,
+                  114,
+                  118,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "tokenMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenMetadataAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  101,
+                  116,
+                  97,
+                  100,
+                  97,
+                  116,
+                  97
+                ]
+              },
+              {
+                "kind": "const",
+                "value": [
+                  11,
+                  112,
+                  101,
+                  177,
+                  227,
+                  209,
+                  124,
+                  69,
+                  56,
+                  157,
+                  82,
+                  127,
+                  107,
+                  4,
+                  195,
+                  205,
+                  88,
+                  184,
+                  108,
+                  115,
+                  26,
+                  160,
+                  253,
+                  181,
+                  73,
+                  182,
+                  209,
+                  188,
+                  3,
+                  248,
+                  41,
+                  70
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "tokenMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                11,
+                112,
+                101,
+                177,
+                227,
+                209,
+                124,
+                69,
+                56,
+                157,
+                82,
+                127,
+                107,
+                4,
+                195,
+                205,
+                88,
+                184,
+                108,
+                115,
+                26,
+                160,
+                253,
+                181,
+                73,
+                182,
+                209,
+                188,
+                3,
+                248,
+                41,
+                70
+              ]
+            }
+          }
+        },
+        {
+          "name": "mplTokenMetadataProgram",
+          "address": "metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "name",
+          "type": "string"
+        },
+        {
+          "name": "symbol",
+          "type": "string"
+        },
+        {
+          "name": "uri",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "withdraw",
+      "discriminator": [
+        183,
+        18,
+        70,
+        156,
+        148,
+        109,
+        161,
+        34
+      ],
+      "accounts": [
+        {
+          "name": "globalConfig",
+          "writable": true
+        },
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "tokenMint"
+        },
+        {
+          "name": "bondingCurve",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  111,
+                  110,
+                  100,
+                  105,
+                  110,
+                  103,
+                  95,
+                  99,
+                  117,
+                  114,
+                  118,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "tokenMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "curveVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  117,
+                  114,
+                  118,
+                  101,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "bondingCurve"
+              }
+            ]
+          }
+        },
+        {
+          "name": "curveVaultAta",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "curveVault"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "tokenMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "adminAta",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "admin"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "tokenMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "globalVault",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  108,
+                  111,
+                  98,
+                  97,
+                  108
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenMetadataAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  101,
+                  116,
+                  97,
+                  100,
+                  97,
+                  116,
+                  97
+                ]
+              },
+              {
+                "kind": "const",
+                "value": [
+                  11,
+                  112,
+                  101,
+                  177,
+                  227,
+                  209,
+                  124,
+                  69,
+                  56,
+                  157,
+                  82,
+                  127,
+                  107,
+                  4,
+                  195,
+                  205,
+                  88,
+                  184,
+                  108,
+                  115,
+                  26,
+                  160,
+                  253,
+                  181,
+                  73,
+                  182,
+                  209,
+                  188,
+                  3,
+                  248,
+                  41,
+                  70
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "tokenMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                11,
+                112,
+                101,
+                177,
+                227,
+                209,
+                124,
+                69,
+                56,
+                157,
+                82,
+                127,
+                107,
+                4,
+                195,
+                205,
+                88,
+                184,
+                108,
+                115,
+                26,
+                160,
+                253,
+                181,
+                73,
+                182,
+                209,
+                188,
+                3,
+                248,
+                41,
+                70
+              ]
+            }
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "mplTokenMetadataProgram",
+          "address": "metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": []
+    }
+  ],
+  "accounts": [
+    {
+      "name": "bondingCurve",
+      "discriminator": [
+        23,
+        183,
+        248,
+        55,
+        96,
+        216,
+        172,
+        96
+      ]
+    },
+    {
+      "name": "config",
+      "discriminator": [
+        155,
+        12,
+        170,
+        224,
+        30,
+        250,
+        204,
+        130
+      ]
+    },
+    {
+      "name": "creatorRegistry",
+      "discriminator": [
+        14,
+        189,
+        133,
+        111,
+        190,
+        233,
+        2,
+        236
+      ]
+    },
+    {
+      "name": "curveCandles",
+      "discriminator": [
+        0,
+        203,
+        249,
+        207,
+        247,
+        120,
+        162,
+        224
+      ]
+    },
+    {
+      "name": "pendingConfigChange",
+      "discriminator": [
+        184,
+        206,
+        249,
+        115,
+        181,
+        5,
+        94,
+        185
+      ]
+    },
+    {
+      "name": "presaleReceipt",
+      "discriminator": [
+        198,
+        75,
+        216,
+        240,
+        152,
+        39,
+        85,
+        46
+      ]
+    },
+    {
+      "name": "vestingEscrow",
+      "discriminator": [
+        244,
+        119,
+        183,
+        4,
+        73,
+        116,
+        135,
+        195
+      ]
+    }
+  ],
+  "events": [
+    {
+      "name": "auditEvent",
+      "discriminator": [
+        241,
+        242,
+        94,
+        109,
+        175,
+        205,
+        78,
+        0
+      ]
+    },
+    {
+      "name": "authorityAcceptedEvent",
+      "discriminator": [
+        170,
+        231,
+        141,
+        243,
+        134,
+        202,
+        206,
+        124
+      ]
+    },
+    {
+      "name": "authorityNominatedEvent",
+      "discriminator": [
+        221,
+        110,
+        168,
+        77,
+        91,
+        179,
+        225,
+        133
+      ]
+    },
+    {
+      "name": "authorityRenouncedEvent",
+      "discriminator": [
+        8,
+        185,
+        128,
+        205,
+        178,
+        211,
+        4,
+        133
+      ]
+    },
+    {
+      "name": "completeEvent",
+      "discriminator": [
+        95,
+        114,
+        97,
+        156,
+        212,
+        46,
+        152,
+        8
+      ]
+    },
+    {
+      "name": "configChangeCancelledEvent",
+      "discriminator": [
+        129,
+        4,
+        188,
+        199,
+        225,
+        64,
+        160,
+        213
+      ]
+    },
+    {
+      "name": "configChangeExecutedEvent",
+      "discriminator": [
+        191,
+        110,
+        236,
+        226,
+        50,
+        164,
+        248,
+        167
+      ]
+    },
+    {
+      "name": "configChangeQueuedEvent",
+      "discriminator": [
+        36,
+        148,
+        31,
+        69,
+        95,
+        189,
+        36,
+        211
+      ]
+    },
+    {
+      "name": "configMigratedEvent",
+      "discriminator": [
+        254,
+        66,
+        240,
+        79,
+        195,
+        246,
+        86,
+        72
+      ]
+    },
+    {
+      "name": "configUpdatedEvent",
+      "discriminator": [
+        245,
+        158,
+        129,
+        99,
+        60,
+        100,
+        214,
+        220
+      ]
+    },
+    {
+      "name": "curveParamsUpdatedEvent",
+      "discriminator": [
+        146,
+        208,
+        178,
+        47,
+        95,
+        66,
+        15,
+        21
+      ]
+    },
+    {
+      "name": "curveVaultMigratedEvent",
+      "discriminator": [
+        251,
+        82,
+        150,
+        107,
+        22,
+        201,
+        34,
+        63
+      ]
+    },
+    {
+      "name": "feesSweptEvent",
+      "discriminator": [
+        176,
+        158,
+        209,
+        67,
+        245,
+        224,
+        61,
+        65
+      ]
+    },
+    {
+      "name": "launchEvent",
+      "discriminator": [
+        27,
+        193,
+        47,
+        130,
+        115,
+        92,
+        239,
+        94
+      ]
+    },
+    {
+      "name": "launchFeeDiscountsUpdatedEvent",
+      "discriminator": [
+        69,
+        93,
+        94,
+        45,
+        167,
+        190,
+        216,
+        93
+      ]
+    },
+    {
+      "name": "launchLimitsUpdatedEvent",
+      "discriminator": [
+        232,
+        50,
+        56,
+        247,
+        96,
+        237,
+        162,
+        68
+      ]
+    },
+    {
+      "name": "metadataPolicyUpdatedEvent",
+      "discriminator": [
+        165,
+        248,
+        187,
+        76,
+        130,
+        8,
+        89,
+        93
+      ]
+    },
+    {
+      "name": "metadataUpdatedEvent",
+      "discriminator": [
+        66,
+        19,
+        187,
+        5,
+        244,
+        90,
+        125,
+        113
+      ]
+    },
+    {
+      "name": "nominationCancelledEvent",
+      "discriminator": [
+        87,
+        93,
+        131,
+        85,
+        215,
+        157,
+        33,
+        91
+      ]
+    },
+    {
+      "name": "pausedEvent",
+      "discriminator": [
+        43,
+        14,
+        250,
+        236,
+        116,
+        42,
+        177,
+        89
+      ]
+    },
+    {
+      "name": "roleAcceptedEvent",
+      "discriminator": [
+        21,
+        117,
+        62,
+        63,
+        26,
+        184,
+        111,
+        167
+      ]
+    },
+    {
+      "name": "roleNominatedEvent",
+      "discriminator": [
+        84,
+        66,
+        44,
+        135,
+        241,
+        162,
+        23,
+        245
+      ]
+    },
+    {
+      "name": "roleRevokedEvent",
+      "discriminator": [
+        104,
+        105,
+        52,
+        114,
+        39,
+        94,
+        217,
+        251
+      ]
+    },
+    {
+      "name": "swapEvent",
+      "discriminator": [
+        64,
+        198,
+        205,
+        232,
+        38,
+        8,
+        113,
+        226
+      ]
+    },
+    {
+      "name": "vestedClaimedEvent",
+      "discriminator": [
+        185,
+        244,
+        185,
+        6,
+        161,
+        102,
+        249,
+        116
+      ]
+    },
+    {
+      "name": "withdrawEvent",
+      "discriminator": [
+        22,
+        9,
+        133,
+        26,
+        160,
+        44,
+        71,
+        192
+      ]
+    }
+  ],
+  "errors": [
+    {
+      "code": 6000,
+      "name": "valueTooSmall",
+      "msg": "valueTooSmall"
+    },
+    {
+      "code": 6001,
+      "name": "valueTooLarge",
+      "msg": "valueTooLarge"
+    },
+    {
+      "code": 6002,
+      "name": "valueInvalid",
+      "msg": "valueInvalid"
+    },
+    {
+      "code": 6003,
+      "name": "incorrectConfigAccount",
+      "msg": "incorrectConfigAccount"
+    },
+    {
+      "code": 6004,
+      "name": "incorrectAuthority",
+      "msg": "incorrectAuthority"
+    },
+    {
+      "code": 6005,
+      "name": "overflowOrUnderflowOccurred",
+      "msg": "Overflow or underflow occured"
+    },
+    {
+      "code": 6006,
+      "name": "invalidAmount",
+      "msg": "Amount is invalid"
+    },
+    {
+      "code": 6007,
+      "name": "incorrectTeamWallet",
+      "msg": "Incorrect team wallet address"
+    },
+    {
+      "code": 6008,
+      "name": "curveNotCompleted",
+      "msg": "Curve is not completed"
+    },
+    {
+      "code": 6009,
+      "name": "curveAlreadyCompleted",
+      "msg": "Can not swap after the curve is completed"
+    },
+    {
+      "code": 6010,
+      "name": "mintAuthorityEnabled",
+      "msg": "Mint authority should be revoked"
+    },
+    {
+      "code": 6011,
+      "name": "freezeAuthorityEnabled",
+      "msg": "Freeze authority should be revoked"
+    },
+    {
+      "code": 6012,
+      "name": "returnAmountTooSmall",
+      "msg": "Return amount is too small compared to the minimum received amount"
+    },
+    {
+      "code": 6013,
+      "name": "transactionExpired",
+      "msg": "Transaction expired"
+    },
+    {
+      "code": 6014,
+      "name": "decimalOverflow",
+      "msg": "Decimal overflow"
+    },
+    {
+      "code": 6015,
+      "name": "invalidPresaleConfig",
+      "msg": "Presale settings are invalid"
+    },
+    {
+      "code": 6016,
+      "name": "presaleReceiptRequired",
+      "msg": "Presale receipt account is required during the presale"
+    },
+    {
+      "code": 6017,
+      "name": "notOnPresaleAllowlist",
+      "msg": "Buyer is not on the presale allowlist"
+    },
+    {
+      "code": 6018,
+      "name": "presaleCapExceeded",
+      "msg": "Presale wallet cap exceeded"
+    },
+    {
+      "code": 6019,
+      "name": "curveVaultNotMigrated",
+      "msg": "Curve still uses the global vault, migrate it first"
+    },
+    {
+      "code": 6020,
+      "name": "curveVaultAlreadyMigrated",
+      "msg": "Curve already uses its own vault"
+    },
+    {
+      "code": 6021,
+      "name": "invalidRemainingAccounts",
+      "msg": "Remaining accounts are invalid"
+    },
+    {
+      "code": 6022,
+      "name": "incorrectVaultAccount",
+      "msg": "Vault account does not belong to the curve"
+    },
+    {
+      "code": 6023,
+      "name": "duplicateAccount",
+      "msg": "Account passed more than once"
+    },
+    {
+      "code": 6024,
+      "name": "platformFeeTooHigh",
+      "msg": "Platform fee can not exceed 10000 bps"
+    },
+    {
+      "code": 6025,
+      "name": "feeSplitTooHigh",
+      "msg": "Trading fee and dev fee share can not exceed 10000 bps together"
+    },
+    {
+      "code": 6026,
+      "name": "invalidInitBondingCurve",
+      "msg": "Bonding curve init percentage must be between 0 and 100"
+    },
+    {
+      "code": 6027,
+      "name": "curveLimitTooLow",
+      "msg": "Curve limit must exceed the maximum allowed virtual lamport reserves"
+    },
+    {
+      "code": 6028,
+      "name": "emptyAmountConfig",
+      "msg": "Amount config enum has no options"
+    },
+    {
+      "code": 6029,
+      "name": "invalidAmountRange",
+      "msg": "Amount config range minimum is above its maximum"
+    },
+    {
+      "code": 6030,
+      "name": "devWalletNotSet",
+      "msg": "Dev fees are enabled without a dev wallet"
+    },
+    {
+      "code": 6031,
+      "name": "invalidConfigChangeDelay",
+      "msg": "Config change delay is out of range"
+    },
+    {
+      "code": 6032,
+      "name": "timelockedConfigChange",
+      "msg": "Fees, fee wallets and the change delay have to go through queue_config_change"
+    },
+    {
+      "code": 6033,
+      "name": "configChangeNotReady",
+      "msg": "Config change can not be executed before its eta"
+    },
+    {
+      "code": 6034,
+      "name": "missingRole",
+      "msg": "Signer does not hold the required role"
+    },
+    {
+      "code": 6035,
+      "name": "programPaused",
+      "msg": "Launches and swaps are paused"
+    },
+    {
+      "code": 6036,
+      "name": "invalidNominationValidity",
+      "msg": "Nomination validity is out of range"
+    },
+    {
+      "code": 6037,
+      "name": "nominationExpired",
+      "msg": "Nomination has expired"
+    },
+    {
+      "code": 6038,
+      "name": "noPendingNomination",
+      "msg": "There is no pending nomination"
+    },
+    {
+      "code": 6039,
+      "name": "authorityRenounced",
+      "msg": "Config authority has been renounced"
+    },
+    {
+      "code": 6040,
+      "name": "rootConfigRequired",
+      "msg": "Creating a config tier requires the root config"
+    },
+    {
+      "code": 6041,
+      "name": "configTierMismatch",
+      "msg": "Bonding curve belongs to another config tier"
+    },
+    {
+      "code": 6042,
+      "name": "valueNotOnStep",
+      "msg": "Value is not on the configured step"
+    },
+    {
+      "code": 6043,
+      "name": "invalidAmountStep",
+      "msg": "Amount config step must be above zero"
+    },
+    {
+      "code": 6044,
+      "name": "invalidMetadataPolicy",
+      "msg": "Metadata policy limits are out of range"
+    },
+    {
+      "code": 6045,
+      "name": "nameTooLong",
+      "msg": "Token name is too long"
+    },
+    {
+      "code": 6046,
+      "name": "symbolTooLong",
+      "msg": "Token symbol is too long"
+    },
+    {
+      "code": 6047,
+      "name": "uriTooLong",
+      "msg": "Token uri is too long"
+    },
+    {
+      "code": 6048,
+      "name": "uriSchemeNotAllowed",
+      "msg": "Token uri does not start with an allowed prefix"
+    },
+    {
+      "code": 6049,
+      "name": "invalidSymbolCharacter",
+      "msg": "Token symbol has a character outside the allowed charset"
+    },
+    {
+      "code": 6050,
+      "name": "notCurveCreator",
+      "msg": "Signer is not the creator of the curve"
+    },
+    {
+      "code": 6051,
+      "name": "incorrectMintAccount",
+      "msg": "Mint account is not the pda of the creator and nonce"
+    },
+    {
+      "code": 6052,
+      "name": "mintSignatureRequired",
+      "msg": "Mint keypair has to sign when no mint nonce is given"
+    },
+    {
+      "code": 6053,
+      "name": "launchFeeTooHigh",
+      "msg": "Launch fee is too high"
+    },
+    {
+      "code": 6054,
+      "name": "invalidLaunchFeeDiscounts",
+      "msg": "Launch fee discounts are invalid, too many, above 10000 bps or duplicated"
+    },
+    {
+      "code": 6055,
+      "name": "invalidLaunchRateLimit",
+      "msg": "Launch rate limit is out of range"
+    },
+    {
+      "code": 6056,
+      "name": "launchRateLimited",
+      "msg": "Creator reached the launch limit of the current window"
+    },
+    {
+      "code": 6057,
+      "name": "invalidVestingSchedule",
+      "msg": "Vesting cliff or duration is out of range"
+    },
+    {
+      "code": 6058,
+      "name": "nothingToClaim",
+      "msg": "No vested tokens to claim"
+    },
+    {
+      "code": 6059,
+      "name": "invalidAllocationLimits",
+      "msg": "Allocation limits are out of range"
+    },
+    {
+      "code": 6060,
+      "name": "tooManyAllocations",
+      "msg": "Too many launch allocations"
+    },
+    {
+      "code": 6061,
+      "name": "allocationTooLarge",
+      "msg": "Launch allocation is empty or above the per-recipient cap"
+    },
+    {
+      "code": 6062,
+      "name": "curveShareTooLow",
+      "msg": "Allocations leave the curve below its minimum share"
+    },
+    {
+      "code": 6063,
+      "name": "noFeesToSweep",
+      "msg": "No accrued fees to sweep"
+    },
+    {
+      "code": 6064,
+      "name": "configAlreadyMigrated",
+      "msg": "Config already has the current layout"
+    },
+    {
+      "code": 6065,
+      "name": "migrationOperatorRequired",
+      "msg": "A migration operator has to be assigned first"
+    },
+    {
+      "code": 6066,
+      "name": "launchFeeDiscountReduced",
+      "msg": "Lowering or removing a launch fee discount has to be queued"
+    },
+    {
+      "code": 6067,
+      "name": "curveCandlesRequired",
+      "msg": "Candle account is required for curves with price history"
+    }
+  ],
+  "types": [
+    {
+      "name": "allocationLimits",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "maxAllocations",
+            "type": "u8"
+          },
+          {
+            "name": "maxAllocationBps",
+            "type": "u16"
+          },
+          {
+            "name": "minCurveBps",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "amountConfig",
+      "generics": [
+        {
+          "kind": "type",
+          "name": "t"
+        }
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "range",
+            "fields": [
+              {
+                "name": "min",
+                "type": {
+                  "option": {
+                    "generic": "t"
+                  }
+                }
+              },
+              {
+                "name": "max",
+                "type": {
+                  "option": {
+                    "generic": "t"
+                  }
+                }
+              }
+            ]
+          },
+          {
+            "name": "enum",
+            "fields": [
+              {
+                "vec": {
+                  "generic": "t"
+                }
+              }
+            ]
+          },
+          {
+            "name": "step",
+            "fields": [
+              {
+                "name": "min",
+                "type": {
+                  "generic": "t"
+                }
+              },
+              {
+                "name": "max",
+                "type": {
+                  "generic": "t"
+                }
+              },
+              {
+                "name": "step",
+                "type": {
+                  "generic": "t"
+                }
+              }
+            ]
+          },
+          {
+            "name": "all",
+            "fields": [
+              {
+                "vec": {
+                  "defined": {
+                    "name": "amountRule",
+                    "generics": [
+                      {
+                        "kind": "type",
+                        "type": {
+                          "generic": "t"
+                        }
+                      }
+                    ]
+                  }
+                }
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "amountRule",
+      "generics": [
+        {
+          "kind": "type",
+          "name": "t"
+        }
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "range",
+            "fields": [
+              {
+                "name": "min",
+                "type": {
+                  "option": {
+                    "generic": "t"
+                  }
+                }
+              },
+              {
+                "name": "max",
+                "type": {
+                  "option": {
+                    "generic": "t"
+                  }
+                }
+              }
+            ]
+          },
+          {
+            "name": "enum",
+            "fields": [
+              {
+                "vec": {
+                  "generic": "t"
+                }
+              }
+            ]
+          },
+          {
+            "name": "step",
+            "fields": [
+              {
+                "name": "min",
+                "type": {
+                  "generic": "t"
+                }
+              },
+              {
+                "name": "max",
+                "type": {
+                  "generic": "t"
+                }
+              },
+              {
+                "name": "step",
+                "type": {
+                  "generic": "t"
+                }
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "auditEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "curves",
+            "type": "u32"
+          },
+          {
+            "name": "solObligation",
+            "type": "u64"
+          },
+          {
+            "name": "solBalance",
+            "type": "u64"
+          },
+          {
+            "name": "rentReserve",
+            "type": "u64"
+          },
+          {
+            "name": "solSurplus",
+            "type": "u64"
+          },
+          {
+            "name": "solShortfall",
+            "type": "u64"
+          },
+          {
+            "name": "tokenObligation",
+            "type": "u64"
+          },
+          {
+            "name": "tokenBalance",
+            "type": "u64"
+          },
+          {
+            "name": "tokenSurplus",
+            "type": "u64"
+          },
+          {
+            "name": "tokenShortfall",
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "authorityAcceptedEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "previousAuthority",
+            "type": "pubkey"
+          },
+          {
+            "name": "newAuthority",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "authorityNominatedEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "pendingAuthority",
+            "type": "pubkey"
+          },
+          {
+            "name": "expiresAt",
+            "type": "i64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "authorityRenouncedEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "previousAuthority",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "bondingCurve",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "tokenMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "creator",
+            "type": "pubkey"
+          },
+          {
+            "name": "initLamport",
+            "type": "u64"
+          },
+          {
+            "name": "reserveLamport",
+            "type": "u64"
+          },
+          {
+            "name": "reserveToken",
+            "type": "u64"
+          },
+          {
+            "name": "curveLimit",
+            "type": "u64"
+          },
+          {
+            "name": "isCompleted",
+            "type": "bool"
+          },
+          {
+            "name": "presaleMerkleRoot",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "presaleEndTs",
+            "type": "i64"
+          },
+          {
+            "name": "presaleWalletCap",
+            "type": "u64"
+          },
+          {
+            "name": "hasCurveVault",
+            "type": "bool"
+          },
+          {
+            "name": "curveVaultBump",
+            "type": "u8"
+          },
+          {
+            "name": "priceCumulative",
+            "type": "u128"
+          },
+          {
+            "name": "lastPriceUpdateTs",
+            "type": "i64"
+          },
+          {
+            "name": "configTier",
+            "type": "u8"
+          },
+          {
+            "name": "accruedPlatformFee",
+            "type": "u64"
+          },
+          {
+            "name": "accruedDevFee",
+            "type": "u64"
+          },
+          {
+            "name": "hasCandles",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "candle",
+      "serialization": "bytemuck",
+      "repr": {
+        "kind": "c"
+      },
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "openTs",
+            "type": "i64"
+          },
+          {
+            "name": "open",
+            "type": "u64"
+          },
+          {
+            "name": "high",
+            "type": "u64"
+          },
+          {
+            "name": "low",
+            "type": "u64"
+          },
+          {
+            "name": "close",
+            "type": "u64"
+          },
+          {
+            "name": "volume",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "completeEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "user",
+            "type": "pubkey"
+          },
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "bondingCurve",
+            "type": "pubkey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "config",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "pendingAuthority",
+            "type": "pubkey"
+          },
+          {
+            "name": "teamWallet",
+            "type": "pubkey"
+          },
+          {
+            "name": "devWallet",
+            "type": "pubkey"
+          },
+          {
+            "name": "initBondingCurve",
+            "type": "f64"
+          },
+          {
+            "name": "platformBuyFee",
+            "type": "u128"
+          },
+          {
+            "name": "platformSellFee",
+            "type": "u128"
+          },
+          {
+            "name": "tradingFeeBps",
+            "type": "u16"
+          },
+          {
+            "name": "devFeeShareBps",
+            "type": "u16"
+          },
+          {
+            "name": "devFeeEnabled",
+            "type": "bool"
+          },
+          {
+            "name": "curveLimit",
+            "type": "u64"
+          },
+          {
+            "name": "lamportAmountConfig",
+            "type": {
+              "defined": {
+                "name": "amountConfig",
+                "generics": [
+                  {
+                    "kind": "type",
+                    "type": "u64"
+                  }
+                ]
+              }
+            }
+          },
+          {
+            "name": "tokenSupplyConfig",
+            "type": {
+              "defined": {
+                "name": "amountConfig",
+                "generics": [
+                  {
+                    "kind": "type",
+                    "type": "u64"
+                  }
+                ]
+              }
+            }
+          },
+          {
+            "name": "tokenDecimalsConfig",
+            "type": {
+              "defined": {
+                "name": "amountConfig",
+                "generics": [
+                  {
+                    "kind": "type",
+                    "type": "u8"
+                  }
+                ]
+              }
+            }
+          },
+          {
+            "name": "configChangeDelay",
+            "type": "i64"
+          },
+          {
+            "name": "feeAdmin",
+            "type": {
+              "defined": {
+                "name": "roleAssignment"
+              }
+            }
+          },
+          {
+            "name": "pauseGuardian",
+            "type": {
+              "defined": {
+                "name": "roleAssignment"
+              }
+            }
+          },
+          {
+            "name": "migrationOperator",
+            "type": {
+              "defined": {
+                "name": "roleAssignment"
+              }
+            }
+          },
+          {
+            "name": "launchModerator",
+            "type": {
+              "defined": {
+                "name": "roleAssignment"
+              }
+            }
+          },
+          {
+            "name": "paused",
+            "type": "bool"
+          },
+          {
+            "name": "pendingAuthorityNominatedAt",
+            "type": "i64"
+          },
+          {
+            "name": "nominationValidity",
+            "type": "i64"
+          },
+          {
+            "name": "tierId",
+            "type": "u8"
+          },
+          {
+            "name": "curveLimitConfig",
+            "type": {
+              "defined": {
+                "name": "amountConfig",
+                "generics": [
+                  {
+                    "kind": "type",
+                    "type": "u64"
+                  }
+                ]
+              }
+            }
+          },
+          {
+            "name": "metadataPolicy",
+            "type": {
+              "defined": {
+                "name": "metadataPolicy"
+              }
+            }
+          },
+          {
+            "name": "launchFeeLamports",
+            "type": "u64"
+          },
+          {
+            "name": "launchFeeDiscounts",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "launchFeeDiscount"
+                }
+              }
+            }
+          },
+          {
+            "name": "launchRateLimit",
+            "type": {
+              "defined": {
+                "name": "launchRateLimit"
+              }
+            }
+          },
+          {
+            "name": "teamVesting",
+            "type": {
+              "defined": {
+                "name": "vestingSchedule"
+              }
+            }
+          },
+          {
+            "name": "allocationLimits",
+            "type": {
+              "defined": {
+                "name": "allocationLimits"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "configChange",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "fees",
+            "fields": [
+              {
+                "defined": {
+                  "name": "feeParams"
+                }
+              }
+            ]
+          },
+          {
+            "name": "feeWallets",
+            "fields": [
+              {
+                "defined": {
+                  "name": "feeWalletParams"
+                }
+              }
+            ]
+          },
+          {
+            "name": "delay",
+            "fields": [
+              "i64"
+            ]
+          },
+          {
+            "name": "launchFeeDiscount",
+            "fields": [
+              {
+                "defined": {
+                  "name": "launchFeeDiscount"
+                }
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "configChangeCancelledEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "change",
+            "type": {
+              "defined": {
+                "name": "configChange"
+              }
+            }
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "configChangeExecutedEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "old",
+            "type": {
+              "defined": {
+                "name": "configChange"
+              }
+            }
+          },
+          {
+            "name": "new",
+            "type": {
+              "defined": {
+                "name": "configChange"
+              }
+            }
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "configChangeQueuedEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "change",
+            "type": {
+              "defined": {
+                "name": "configChange"
+              }
+            }
+          },
+          {
+            "name": "eta",
+            "type": "i64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "configMigratedEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "payer",
+            "type": "pubkey"
+          },
+          {
+            "name": "config",
+            "type": {
+              "defined": {
+                "name": "config"
+              }
+            }
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "configUpdatedEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "config",
+            "type": {
+              "defined": {
+                "name": "config"
+              }
+            }
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "creatorRegistry",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "creator",
+            "type": "pubkey"
+          },
+          {
+            "name": "launches",
+            "type": "u64"
+          },
+          {
+            "name": "graduated",
+            "type": "u64"
+          },
+          {
+            "name": "totalVolume",
+            "type": "u64"
+          },
+          {
+            "name": "lastMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "lastLaunchTs",
+            "type": "i64"
+          },
+          {
+            "name": "recentLaunchTs",
+            "type": {
+              "array": [
+                "i64",
+                16
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "curveCandles",
+      "serialization": "bytemuck",
+      "repr": {
+        "kind": "c"
+      },
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bondingCurve",
+            "type": "pubkey"
+          },
+          {
+            "name": "interval",
+            "type": "i64"
+          },
+          {
+            "name": "head",
+            "type": "u64"
+          },
+          {
+            "name": "count",
+            "type": "u64"
+          },
+          {
+            "name": "candles",
+            "type": {
+              "array": [
+                {
+                  "defined": {
+                    "name": "candle"
+                  }
+                },
+                200
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "curveParams",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "initBondingCurve",
+            "type": "f64"
+          },
+          {
+            "name": "curveLimit",
+            "type": "u64"
+          },
+          {
+            "name": "teamVesting",
+            "type": {
+              "defined": {
+                "name": "vestingSchedule"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "curveParamsUpdatedEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "old",
+            "type": {
+              "defined": {
+                "name": "curveParams"
+              }
+            }
+          },
+          {
+            "name": "new",
+            "type": {
+              "defined": {
+                "name": "curveParams"
+              }
+            }
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "curveVaultMigratedEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "bondingCurve",
+            "type": "pubkey"
+          },
+          {
+            "name": "curveVault",
+            "type": "pubkey"
+          },
+          {
+            "name": "lamportAmount",
+            "type": "u64"
+          },
+          {
+            "name": "tokenAmount",
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "feeParams",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "platformBuyFee",
+            "type": "u128"
+          },
+          {
+            "name": "platformSellFee",
+            "type": "u128"
+          },
+          {
+            "name": "tradingFeeBps",
+            "type": "u16"
+          },
+          {
+            "name": "devFeeShareBps",
+            "type": "u16"
+          },
+          {
+            "name": "devFeeEnabled",
+            "type": "bool"
+          },
+          {
+            "name": "launchFeeLamports",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "feeWalletParams",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "teamWallet",
+            "type": "pubkey"
+          },
+          {
+            "name": "devWallet",
+            "type": "pubkey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "feesSweptEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "bondingCurve",
+            "type": "pubkey"
+          },
+          {
+            "name": "teamWallet",
+            "type": "pubkey"
+          },
+          {
+            "name": "platformFee",
+            "type": "u64"
+          },
+          {
+            "name": "devFee",
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "graduationMetadata",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "keep"
+          },
+          {
+            "name": "transferToCreator"
+          },
+          {
+            "name": "makeImmutable"
+          }
+        ]
+      }
+    },
+    {
+      "name": "launchAllocation",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "recipient",
+            "type": "pubkey"
+          },
+          {
+            "name": "bps",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "launchEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "creator",
+            "type": "pubkey"
+          },
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "bondingCurve",
+            "type": "pubkey"
+          },
+          {
+            "name": "decimals",
+            "type": "u8"
+          },
+          {
+            "name": "tokenSupply",
+            "type": "u64"
+          },
+          {
+            "name": "curveTokenAmount",
+            "type": "u64"
+          },
+          {
+            "name": "teamTokenAmount",
+            "type": "u64"
+          },
+          {
+            "name": "virtualLamportReserves",
+            "type": "u64"
+          },
+          {
+            "name": "curveLimit",
+            "type": "u64"
+          },
+          {
+            "name": "presaleEndTs",
+            "type": "i64"
+          },
+          {
+            "name": "configTier",
+            "type": "u8"
+          },
+          {
+            "name": "launchFee",
+            "type": "u64"
+          },
+          {
+            "name": "allocations",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "launchAllocation"
+                }
+              }
+            }
+          },
+          {
+            "name": "allocatedTokenAmount",
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "launchFeeDiscount",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "creator",
+            "type": "pubkey"
+          },
+          {
+            "name": "discountBps",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "launchFeeDiscountsUpdatedEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "old",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "launchFeeDiscount"
+                }
+              }
+            }
+          },
+          {
+            "name": "new",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "launchFeeDiscount"
+                }
+              }
+            }
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "launchLimits",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "lamportAmountConfig",
+            "type": {
+              "defined": {
+                "name": "amountConfig",
+                "generics": [
+                  {
+                    "kind": "type",
+                    "type": "u64"
+                  }
+                ]
+              }
+            }
+          },
+          {
+            "name": "tokenSupplyConfig",
+            "type": {
+              "defined": {
+                "name": "amountConfig",
+                "generics": [
+                  {
+                    "kind": "type",
+                    "type": "u64"
+                  }
+                ]
+              }
+            }
+          },
+          {
+            "name": "tokenDecimalsConfig",
+            "type": {
+              "defined": {
+                "name": "amountConfig",
+                "generics": [
+                  {
+                    "kind": "type",
+                    "type": "u8"
+                  }
+                ]
+              }
+            }
+          },
+          {
+            "name": "curveLimitConfig",
+            "type": {
+              "defined": {
+                "name": "amountConfig",
+                "generics": [
+                  {
+                    "kind": "type",
+                    "type": "u64"
+                  }
+                ]
+              }
+            }
+          },
+          {
+            "name": "launchRateLimit",
+            "type": {
+              "defined": {
+                "name": "launchRateLimit"
+              }
+            }
+          },
+          {
+            "name": "allocationLimits",
+            "type": {
+              "defined": {
+                "name": "allocationLimits"
+              }
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6005,
-      "name": "overflowOrUnderflowOccurred",
-      "msg": "Overflow or underflow occured"
+      "name": "launchLimitsUpdatedEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "old",
+            "type": {
+              "defined": {
+                "name": "launchLimits"
+              }
+            }
+          },
+          {
+            "name": "new",
+            "type": {
+              "defined": {
+                "name": "launchLimits"
+              }
+            }
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6006,
-      "name": "invalidAmount",
-      "msg": "Amount is invalid"
+      "name": "launchRateLimit",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "maxLaunches",
+            "type": "u8"
+          },
+          {
+            "name": "window",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6007,
-      "name": "incorrectTeamWallet",
-      "msg": "Incorrect team wallet address"
+      "name": "metadataPolicy",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "maxNameLen",
+            "type": "u8"
+          },
+          {
+            "name": "maxSymbolLen",
+            "type": "u8"
+          },
+          {
+            "name": "maxUriLen",
+            "type": "u16"
+          },
+          {
+            "name": "allowedUriPrefixes",
+            "type": {
+              "vec": "string"
+            }
+          },
+          {
+            "name": "symbolCharset",
+            "type": {
+              "option": "string"
+            }
+          },
+          {
+            "name": "onGraduation",
+            "type": {
+              "defined": {
+                "name": "graduationMetadata"
+              }
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6008,
-      "name": "curveNotCompleted",
-      "msg": "Curve is not completed"
+      "name": "metadataPolicyUpdatedEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "old",
+            "type": {
+              "defined": {
+                "name": "metadataPolicy"
+              }
+            }
+          },
+          {
+            "name": "new",
+            "type": {
+              "defined": {
+                "name": "metadataPolicy"
+              }
+            }
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6009,
-      "name": "curveAlreadyCompleted",
-      "msg": "Can not swap after the curve is completed"
+      "name": "metadataUpdatedEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "creator",
+            "type": "pubkey"
+          },
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "bondingCurve",
+            "type": "pubkey"
+          },
+          {
+            "name": "name",
+            "type": "string"
+          },
+          {
+            "name": "symbol",
+            "type": "string"
+          },
+          {
+            "name": "uri",
+            "type": "string"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "nominationCancelledEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "role",
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "role"
+                }
+              }
+            }
+          },
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "cancelledNominee",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "pausedEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "guardian",
+            "type": "pubkey"
+          },
+          {
+            "name": "paused",
+            "type": "bool"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "pendingConfigChange",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "config",
+            "type": "pubkey"
+          },
+          {
+            "name": "change",
+            "type": {
+              "defined": {
+                "name": "configChange"
+              }
+            }
+          },
+          {
+            "name": "eta",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "presaleConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "merkleRoot",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "endTs",
+            "type": "i64"
+          },
+          {
+            "name": "walletCap",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "presaleReceipt",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bondingCurve",
+            "type": "pubkey"
+          },
+          {
+            "name": "buyer",
+            "type": "pubkey"
+          },
+          {
+            "name": "purchasedLamports",
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 6010,
-      "name": "mintAuthorityEnabled",
-      "msg": "Mint authority should be revoked"
+      "name": "role",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "feeAdmin"
+          },
+          {
+            "name": "pauseGuardian"
+          },
+          {
+            "name": "migrationOperator"
+          },
+          {
+            "name": "launchModerator"
+          }
+        ]
+      }
     },
     {
-      "code": 6011,
-      "name": "freezeAuthorityEnabled",
-      "msg": "Freeze authority should be revoked"
+      "name": "roleAcceptedEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "role",
+            "type": {
+              "defined": {
+                "name": "role"
+              }
+            }
+          },
+          {
+            "name": "previousHolder",
+            "type": "pubkey"
+          },
+          {
+            "name": "newHolder",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6012,
-      "name": "returnAmountTooSmall",
-      "msg": "Return amount is too small compared to the minimum received amount"
+      "name": "roleAssignment",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "holder",
+            "type": "pubkey"
+          },
+          {
+            "name": "pending",
+            "type": "pubkey"
+          },
+          {
+            "name": "nominatedAt",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6013,
-      "name": "transactionExpired",
-      "msg": "Transaction expired"
+      "name": "roleNominatedEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "role",
+            "type": {
+              "defined": {
+                "name": "role"
+              }
+            }
+          },
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "pendingHolder",
+            "type": "pubkey"
+          },
+          {
+            "name": "expiresAt",
+            "type": "i64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6014,
-      "name": "decimalOverflow",
-      "msg": "Decimal overflow"
-    }
-  ],
-  "types": [
-    {
-      "name": "amountConfig",
-      "generics": [
-        {
-          "kind": "type",
-          "name": "t"
-        }
-      ],
+      "name": "roleRevokedEvent",
       "type": {
-        "kind": "enum",
-        "variants": [
+        "kind": "struct",
+        "fields": [
           {
-            "name": "range",
-            "fields": [
-              {
-                "name": "min",
-                "type": {
-                  "option": {
-                    "generic": "t"
-                  }
-                }
-              },
-              {
-                "name": "max",
-                "type": {
-                  "option": {
-                    "generic": "t"
-                  }
-                }
+            "name": "role",
+            "type": {
+              "defined": {
+                "name": "role"
               }
-            ]
+            }
           },
           {
-            "name": "enum",
-            "fields": [
-              {
-                "vec": {
-                  "generic": "t"
-                }
-              }
-            ]
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "previousHolder",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
           }
         ]
       }
     },
     {
-      "name": "bondingCurve",
+      "name": "swapEvent",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "tokenMint",
+            "name": "user",
             "type": "pubkey"
           },
           {
-            "name": "creator",
+            "name": "mint",
             "type": "pubkey"
           },
           {
-            "name": "initLamport",
+            "name": "bondingCurve",
+            "type": "pubkey"
+          },
+          {
+            "name": "direction",
+            "type": "u8"
+          },
+          {
+            "name": "amountIn",
             "type": "u64"
           },
           {
-            "name": "reserveLamport",
+            "name": "amountOut",
+            "type": "u64"
+          },
+          {
+            "name": "platformFee",
+            "type": "u64"
+          },
+          {
+            "name": "devFee",
             "type": "u64"
           },
           {
@@ -1662,23 +6577,23 @@ export type ApeOrDie = {
             "type": "u64"
           },
           {
-            "name": "curveLimit",
+            "name": "reserveLamport",
             "type": "u64"
           },
           {
-            "name": "isCompleted",
-            "type": "bool"
+            "name": "timestamp",
+            "type": "i64"
           }
         ]
       }
     },
     {
-      "name": "completeEvent",
+      "name": "vestedClaimedEvent",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "user",
+            "name": "teamWallet",
             "type": "pubkey"
           },
           {
@@ -1688,100 +6603,118 @@ export type ApeOrDie = {
           {
             "name": "bondingCurve",
             "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "claimedAmount",
+            "type": "u64"
+          },
+          {
+            "name": "totalAmount",
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
           }
         ]
       }
     },
     {
-      "name": "config",
+      "name": "vestingEscrow",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "authority",
+            "name": "bondingCurve",
             "type": "pubkey"
           },
           {
-            "name": "pendingAuthority",
+            "name": "mint",
             "type": "pubkey"
           },
           {
-            "name": "teamWallet",
-            "type": "pubkey"
+            "name": "totalAmount",
+            "type": "u64"
           },
           {
-            "name": "devWallet",
-            "type": "pubkey"
+            "name": "claimedAmount",
+            "type": "u64"
           },
           {
-            "name": "initBondingCurve",
-            "type": "f64"
+            "name": "startTs",
+            "type": "i64"
           },
           {
-            "name": "platformBuyFee",
-            "type": "u128"
+            "name": "schedule",
+            "type": {
+              "defined": {
+                "name": "vestingSchedule"
+              }
+            }
           },
           {
-            "name": "platformSellFee",
-            "type": "u128"
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "vestingSchedule",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "cliff",
+            "type": "i64"
           },
           {
-            "name": "tradingFeeBps",
-            "type": "u16"
+            "name": "duration",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "withdrawEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "admin",
+            "type": "pubkey"
           },
           {
-            "name": "devFeeShareBps",
-            "type": "u16"
+            "name": "mint",
+            "type": "pubkey"
           },
           {
-            "name": "devFeeEnabled",
-            "type": "bool"
+            "name": "bondingCurve",
+            "type": "pubkey"
           },
           {
-            "name": "curveLimit",
+            "name": "lamportAmount",
             "type": "u64"
           },
           {
-            "name": "lamportAmountConfig",
-            "type": {
-              "defined": {
-                "name": "amountConfig",
-                "generics": [
-                  {
-                    "kind": "type",
-                    "type": "u64"
-                  }
-                ]
-              }
-            }
+            "name": "tokenAmount",
+            "type": "u64"
           },
           {
-            "name": "tokenSupplyConfig",
+            "name": "graduationMetadata",
             "type": {
               "defined": {
-                "name": "amountConfig",
-                "generics": [
-                  {
-                    "kind": "type",
-                    "type": "u64"
-                  }
-                ]
+                "name": "graduationMetadata"
               }
             }
           },
           {
-            "name": "tokenDecimalsConfig",
-            "type": {
-              "defined": {
-                "name": "amountConfig",
-                "generics": [
-                  {
-                    "kind": "type",
-                    "type": "u8"
-                  }
-                ]
-              }
-            }
+            "name": "timestamp",
+            "type": "i64"
           }
         ]
       }